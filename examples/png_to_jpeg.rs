@@ -42,14 +42,12 @@ fn main() {
     .unwrap();
 
     //optional parameters
-    let options = ops::JpegsaveOptions {
-        q: 90,
-        background: vec![255.0],
-        interlace: true,
-        optimize_coding: true,
-        optimize_scans: true,
-        ..ops::JpegsaveOptions::default()
-    };
+    let options = ops::JpegsaveOptions::new()
+        .q(90)
+        .background(&[255.0])
+        .interlace(true)
+        .optimize_coding(true)
+        .optimize_scans(true);
 
     // alternatively you can use `jpegsave` that will use the default options
     match ops::jpegsave_with_opts(
@@ -60,10 +58,9 @@ fn main() {
         ),
         &options,
     ) {
-        Err(_) => println!(
+        Err(err) => println!(
             "error: {}",
-            app.error_buffer()
-                .unwrap()
+            err
         ),
         Ok(_) => println!("png_to_jpeg.jpg was created within the examples directory!"),
     }
@@ -16,6 +16,7 @@ struct Operation {
     required: Vec<Parameter>,
     optional: Vec<Parameter>,
     output: Vec<Parameter>,
+    optional_output: Vec<Parameter>,
 }
 
 impl Operation {
@@ -62,7 +63,14 @@ impl Operation {
 
     fn doc(&self, with_optional: bool) -> String {
         let base = self.doc_base();
-        let returns = self.doc_returns();
+        let returns = if with_optional && !self.optional_output.is_empty() {
+            format!(
+                "/// returns `{}Output` - required and optional outputs",
+                self.name.to_upper_camel_case()
+            )
+        } else {
+            self.doc_returns()
+        };
         if !self.optional.is_empty() && with_optional {
             format!("{}\n{}\n{}", base, self.doc_optional(), returns)
         } else {
@@ -74,41 +82,77 @@ impl Operation {
         let declarations = self
             .optional
             .iter()
-            .map(|p| format!("{}\npub {}", p.doc_struct(), p.struct_declaration()))
+            .map(|p| p.struct_declaration())
             .collect::<Vec<_>>()
             .join(",\n");
-        let defaults = self
+        let setters = self
             .optional
             .iter()
-            .map(|p| p.default())
+            .map(|p| p.setter())
             .collect::<Vec<_>>()
-            .join(",\n");
+            .join("\n\n");
         format!(
             r#"
             /// Options for {} operation
-            #[derive(Clone, Debug)]
+            #[derive(Clone, Debug, Default)]
             pub struct {}Options {{
                 {}
             }}
 
-            impl std::default::Default for {}Options {{
-                fn default() -> Self {{
-                    {}Options {{
-                        {}
-                    }}
+            impl {}Options {{
+                /// options with no argument set, so libvips uses its own defaults
+                pub fn new() -> Self {{
+                    Self::default()
                 }}
+
+                {}
             }}
             "#,
             self.name,
             self.name.to_upper_camel_case(),
             declarations,
             self.name.to_upper_camel_case(),
+            setters
+        )
+    }
+
+    fn struct_output(&self) -> String {
+        let declarations = self
+            .output
+            .iter()
+            .map(|p| {
+                format!(
+                    "{}\npub {}: {}",
+                    p.doc_output(&p.param_type.struct_type()),
+                    p.name,
+                    p.param_type.struct_type()
+                )
+            })
+            .chain(self.optional_output.iter().map(|p| {
+                format!(
+                    "{}\npub {}: {}",
+                    p.doc_output(&p.optional_out_type()),
+                    p.name,
+                    p.optional_out_type()
+                )
+            }))
+            .collect::<Vec<_>>()
+            .join(",\n");
+        format!(
+            r#"
+            /// Outputs of {} operation
+            #[derive(Clone, Debug)]
+            pub struct {}Output {{
+                {}
+            }}
+            "#,
+            self.name,
             self.name.to_upper_camel_case(),
-            defaults
+            declarations
         )
     }
 
-    fn get_variables(&self, with_optional: bool) -> String {
+    fn get_variables(&self) -> String {
         let in_declaration = self
             .required
             .iter()
@@ -121,39 +165,21 @@ impl Operation {
             .map(|p| p.declare_out_variable())
             .collect::<Vec<_>>()
             .join("\n");
-        let opt_declaration = if with_optional {
-            self.optional
-                .iter()
-                .map(|p| {
-                    format!(
-                        r#"
-            {}
-            {}"#,
-                        p.declare_in_variable_optional(&self.name.to_snake_case()),
-                        p.declare_opt_name()
-                    )
-                })
-                .collect::<Vec<_>>()
-                .join("\n")
-        } else {
-            String::new()
-        };
         format!(
             r#"
         {}
         {}
-        {}
         "#,
-            in_declaration, out_declaration, opt_declaration
+            in_declaration, out_declaration
         )
     }
 
-    fn get_params(&self, with_optonal: bool) -> String {
+    fn get_params(&self) -> String {
         let mut all_params = Vec::new();
         all_params.append(&mut self.required.clone());
         all_params.append(&mut self.output.clone());
         all_params.sort_by_key(|p| p.order);
-        let params = all_params
+        all_params
             .iter()
             .map(|p| {
                 if self.output.contains(p) {
@@ -164,7 +190,9 @@ impl Operation {
                         ParamType::ArrayInt | ParamType::ArrayDouble | ParamType::ArrayImage => {
                             format!("&mut {}_out, &mut {}_array_size", p.name, p.name)
                         }
-                        ParamType::VipsImage { prev: Some(prev) } => {
+                        ParamType::VipsImage {
+                            prev: Some(prev), ..
+                        } => {
                             format!("&mut {}_out, {}_len", p.name, prev)
                         }
                         _ => format!("&mut {}_out", p.name),
@@ -183,23 +211,63 @@ impl Operation {
                 }
             })
             .collect::<Vec<_>>()
-            .join(", ");
-        if with_optonal {
-            format!(
-                "{},{}",
-                params,
-                self.optional
-                    .iter()
-                    .map(|p| p.opt_param_pair())
-                    .collect::<Vec<_>>()
-                    .join(", ")
-            )
-        } else {
-            params
+            .join(", ")
+    }
+
+    // the images are checked against the load policy: the size of the input is checked first, the
+    // loaders check the header they read before returning, the thumbnails load the header on their
+    // own before shrinking
+    fn load_check(&self) -> &'static str {
+        match self.vips_operation.as_str() {
+            "VipsThumbnailFile" => "utils::check_load_file(filename)?;",
+            "VipsThumbnailBuffer" => "utils::check_load_buffer(buffer)?;",
+            "VipsThumbnailSource" => "utils::check_load_source(source)?;",
+            name if self.is_load() && name.ends_with("Buffer") => "utils::check_load_bytes(buffer.len())?;",
+            _ if self.is_load() && self.has_required("filename") => "utils::check_load_file_bytes(filename)?;",
+            _ if self.is_load() && self.has_required("source") => "utils::check_load_source_bytes(source)?;",
+            _ => "",
         }
     }
 
-    fn method_body(&self, with_optional: bool) -> String {
+    // the images modified in place are made private first, so the clones of an image and the
+    // images computed from it never see the change
+    fn prologue(&self) -> String {
+        self.required
+            .iter()
+            .filter(|p| matches!(p.param_type, ParamType::VipsImage { modify: true, .. }))
+            .map(|p| format!("{}.make_private()?;", p.name))
+            .chain(std::iter::once(self.load_check().to_string()))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    // the errors carry the filename of the file loaded, or of the input image when it was loaded
+    // from a file. a cancelled evaluation of the input image is reported as such, and so is a
+    // source read past the byte limit of the load policy
+    fn error_filename(&self) -> String {
+        let is_file = self.vips_operation.starts_with("VipsForeignLoad") || self.vips_operation == "VipsThumbnailFile";
+        if is_file && self.required.iter().any(|p| p.name == "filename") {
+            return String::from(".map_err(|e| utils::with_filename(e, filename))");
+        }
+        if (self.is_load() || self.vips_operation == "VipsThumbnailSource") && self.has_required("source") {
+            return String::from(".map_err(|e| utils::with_source_limit(e, source))");
+        }
+        self.required
+            .iter()
+            .find(|p| ["inp", "left"].contains(&p.name.as_str()) && matches!(p.param_type, ParamType::VipsImage { .. }))
+            .map(|p| format!(".map_err(|e| utils::with_input_image(e, {}))", p.name))
+            .unwrap_or_default()
+    }
+
+    fn has_required(&self, name: &str) -> bool {
+        self.required.iter().any(|p| p.name == name)
+    }
+
+    fn is_load(&self) -> bool {
+        self.vips_operation.starts_with("VipsForeignLoad") && self.output.iter().any(|p| p.name == "out")
+    }
+
+    fn method_body(&self) -> String {
         let out_tuple = self
             .output
             .iter()
@@ -213,19 +281,104 @@ impl Operation {
         } else {
             out_tuple
         };
+        let checked = if self.is_load() { ".and_then(utils::checked_load)" } else { "" };
         format!(
             r#"
+        {}
         unsafe {{
             {}
             let vips_op_response = bindings::vips_{}({}, NULL);
-            utils::result(vips_op_response, || {}, Error::{}Error)
+            utils::result(vips_op_response, || {}, Error::{}Error){}{}
         }}
         "#,
-            self.get_variables(with_optional),
+            self.prologue(),
+            self.get_variables(),
             self.vips_name,
-            self.get_params(with_optional),
+            self.get_params(),
             out_result,
-            self.name.to_upper_camel_case()
+            self.name.to_upper_camel_case(),
+            self.error_filename(),
+            checked
+        )
+    }
+
+    // the operation is called through the object API, so only the options that were set are
+    // passed to libvips
+    fn method_body_with_opts(&self) -> String {
+        let mut required = self.required.clone();
+        required.sort_by_key(|p| p.order);
+        let set_required = required
+            .iter()
+            .filter_map(|p| {
+                // the coefficients are declared one by one, but libvips takes them as a matrix
+                if self.vips_operation == "VipsAffine" && ["a", "b", "c", "d"].contains(&p.name.as_str()) {
+                    if p.name == "a" {
+                        Some(String::from("operation.set_doubles(\"matrix\", &[a, b, c, d])?;"))
+                    } else {
+                        None
+                    }
+                } else {
+                    Some(p.set_argument(&p.name))
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let options_name = format!("{}_options", self.name.to_snake_case());
+        let set_optional = self
+            .optional
+            .iter()
+            .map(|p| p.set_option(&options_name))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let out_result = if !self.optional_output.is_empty() {
+            let fields = self
+                .output
+                .iter()
+                .map(|p| format!("{}: {}", p.name, p.get_output()))
+                .chain(
+                    self.optional_output
+                        .iter()
+                        .map(|p| format!("{}: {}", p.name, p.get_optional_output())),
+                )
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("Ok({}Output {{ {} }})", self.name.to_upper_camel_case(), fields)
+        } else {
+            let outputs = self
+                .output
+                .iter()
+                .map(|p| p.get_output())
+                .collect::<Vec<_>>()
+                .join(", ");
+            match self.output.len() {
+                0 => String::from("Ok(())"),
+                // the getter already returns a result
+                1 => outputs.trim_end_matches('?').to_string(),
+                _ => format!("Ok(({}))", outputs),
+            }
+        };
+        let arguments = [set_required, set_optional]
+            .iter()
+            .filter(|s| !s.is_empty())
+            .cloned()
+            .collect::<Vec<_>>()
+            .join("\n");
+        let checked = if self.is_load() { "\nutils::check_load(&operation.get_image(\"out\")?)?;" } else { "" };
+        format!(
+            r#"
+        {}
+        let mut operation = utils::VipsOperationWrapper::new("{}", || Error::{}Error)?;
+        {}
+        operation.build(){}?;{}
+        {}
+        "#,
+            self.prologue(),
+            self.vips_name,
+            self.name.to_upper_camel_case(),
+            arguments,
+            self.error_filename(),
+            checked,
+            out_result
         )
     }
 
@@ -235,7 +388,7 @@ impl Operation {
         } else {
             self.name.clone()
         };
-        let params = if with_optional {
+        let params = if with_optional && !self.optional.is_empty() {
             let opt = format!(
                 "{}_options: &{}Options",
                 self.name.to_snake_case(),
@@ -259,7 +412,9 @@ impl Operation {
                 .collect::<Vec<_>>()
                 .join(", ")
         };
-        let return_type = if self.output.is_empty() {
+        let return_type = if with_optional && !self.optional_output.is_empty() {
+            format!("{}Output", self.name.to_upper_camel_case())
+        } else if self.output.is_empty() {
             String::from("()")
         } else if self.output.len() == 1 {
             self.output[0].param_type.struct_type()
@@ -280,6 +435,7 @@ impl Operation {
             .iter()
             .chain(self.optional.iter())
             .chain(self.output.iter())
+            .chain(self.optional_output.iter())
             .map(|p| p.enumeration())
             .filter(|s| !s.is_empty())
             .collect::<Vec<String>>()
@@ -295,22 +451,34 @@ impl Operation {
         "#,
             self.doc(false),
             self.declaration(false),
-            self.method_body(false)
+            self.method_body()
         );
-        if !self.optional.is_empty() {
+        if !self.optional.is_empty() || !self.optional_output.is_empty() {
+            let options = if self.optional.is_empty() {
+                String::new()
+            } else {
+                self.struct_options()
+            };
+            let outputs = if self.optional_output.is_empty() {
+                String::new()
+            } else {
+                self.struct_output()
+            };
             main.push_str(
                 format!(
                     r#"
         {}
         {}
+        {}
         {} {{
             {}
         }}
         "#,
-                    self.struct_options(),
+                    options,
+                    outputs,
                     self.doc(true),
                     self.declaration(true),
-                    self.method_body(true)
+                    self.method_body_with_opts()
                 )
                 .as_str(),
             );
@@ -381,11 +549,7 @@ impl Parameter {
         main_doc
     }
 
-    fn doc_struct(&self) -> String {
-        let type_str = match self.param_type {
-            ParamType::Str => String::from("Option<String>"),
-            _ => self.param_type.struct_type(),
-        };
+    fn doc_setter(&self, type_str: &str) -> String {
         let mut main_doc = format!(
             "/// {}: `{}` -> {}",
             self.name,
@@ -405,51 +569,51 @@ impl Parameter {
             ParamType::Int { .. } => format!(
                 "let {}_in: {} = {};",
                 self.name,
-                self.param_type.vips_in_type(false),
+                self.param_type.vips_in_type(),
                 self.name
             ),
             ParamType::UInt { .. } => format!(
                 "let {}_in: {} = {};",
                 self.name,
-                self.param_type.vips_in_type(false),
+                self.param_type.vips_in_type(),
                 self.name
             ),
             ParamType::Double { .. } => format!(
                 "let {}_in: {} = {};",
                 self.name,
-                self.param_type.vips_in_type(false),
+                self.param_type.vips_in_type(),
                 self.name
             ),
             ParamType::Str => format!(
                 "let {}_in: {} = utils::new_c_string({})?;",
                 self.name,
-                self.param_type.vips_in_type(false),
+                self.param_type.vips_in_type(),
                 self.name
             ),
             ParamType::Bool { .. } => format!(
                 "let {}_in: {} = if {} {{ 1 }} else {{ 0 }};",
                 self.name,
-                self.param_type.vips_in_type(false),
+                self.param_type.vips_in_type(),
                 self.name
             ),
             ParamType::ArrayInt => format!(
                 "let {}_in: {} = {}.as_mut_ptr();",
                 self.name,
-                self.param_type.vips_in_type(false),
+                self.param_type.vips_in_type(),
                 self.name
             ),
             ParamType::ArrayDouble => format!(
                 "let {}_in: {} = {}.as_mut_ptr();",
                 self.name,
-                self.param_type.vips_in_type(false),
+                self.param_type.vips_in_type(),
                 self.name
             ),
             ParamType::ArrayByte => format!(
                 "let {}_in: {} = {}.as_ptr() as {};",
                 self.name,
-                self.param_type.vips_in_type(false),
+                self.param_type.vips_in_type(),
                 self.name,
-                self.param_type.vips_in_type(false)
+                self.param_type.vips_in_type()
             ),
             ParamType::ArrayImage => format!(
                 "let ({}_len, mut {}_in) = {{ let len = {}.len(); let mut input = Vec::new(); for img in {} {{ input.push(img.ctx) }} (len as i32, input) }};",
@@ -465,90 +629,133 @@ impl Parameter {
             | ParamType::VipsInterpolate => format!(
                 "let {}_in: {} = {}.ctx;",
                 self.name,
-                self.param_type.vips_in_type(false),
+                self.param_type.vips_in_type(),
                 self.name
             ),
             ParamType::Enum { .. } => format!(
                 "let {}_in: {} = {} as i32;",
                 self.name,
-                self.param_type.vips_in_type(false),
+                self.param_type.vips_in_type(),
                 self.name
             ),
         }
     }
 
-    fn declare_in_variable_optional(&self, opt_name: &str) -> String {
-        match self.param_type {
-            ParamType::Int { .. } | ParamType::UInt { .. } | ParamType::Double { .. } => format!(
-                "let {}_in: {} = {}_options.{};",
-                self.name,
-                self.param_type.vips_in_type(true),
-                opt_name,
-                self.name
-            ),
-            ParamType::Str => format!(
-                "let {}_in: Option<CString> = {}_options.{}.as_ref().map(|s| utils::new_c_string(s)).transpose()?;",
-                self.name,
-                opt_name,
-                self.name
-            ),
-            ParamType::Bool { .. } => format!(
-                "let {}_in: {} = if {}_options.{} {{ 1 }} else {{ 0 }};",
-                self.name,
-                self.param_type.vips_in_type(true),
-                opt_name,
-                self.name
-            ),
-            ParamType::ArrayDouble | ParamType::ArrayImage | ParamType::ArrayInt => format!(
-                "let {}_wrapper = {}::from(&{}_options.{}[..]); \nlet {}_in = {}_wrapper.ctx;",
-                self.name,
-                self.param_type.vips_in_type(true),
-                opt_name,
-                self.name,
-                self.name,
-                self.name
-            ),
-            ParamType::ArrayByte => format!(
-                "let {}_in: {} = {}_options.{}.as_mut_ptr();",
-                self.name,
-                self.param_type.vips_in_type(true),
-                opt_name,
-                self.name
-            ),
-            ParamType::VipsBlob
-            | ParamType::VipsImage { .. }
+    fn setter(&self) -> String {
+        let (arg_type, value) = match self.param_type {
+            ParamType::Str => (String::from("&str"), format!("String::from({})", self.name)),
+            ParamType::ArrayInt => (String::from("&[i32]"), format!("{}.to_vec()", self.name)),
+            ParamType::ArrayDouble => (String::from("&[f64]"), format!("{}.to_vec()", self.name)),
+            ParamType::ArrayByte | ParamType::VipsBlob => {
+                (String::from("&[u8]"), format!("{}.to_vec()", self.name))
+            }
+            ParamType::ArrayImage => {
+                (String::from("&[VipsImage]"), format!("{}.to_vec()", self.name))
+            }
+            ParamType::VipsImage { .. }
+            | ParamType::VipsInterpolate
             | ParamType::VipsSource
-            | ParamType::VipsTarget
-            | ParamType::VipsInterpolate => format!(
-                "let {}_in: {} = {}_options.{}.ctx;",
-                self.name,
-                self.param_type.vips_in_type(true),
-                opt_name,
-                self.name
+            | ParamType::VipsTarget => (
+                format!("&{}", self.param_type.struct_type()),
+                format!("{}.clone()", self.name),
             ),
-            ParamType::Enum { .. } => format!(
-                "let {}_in: {} = {}_options.{} as i32;",
-                self.name,
-                self.param_type.vips_in_type(true),
-                opt_name,
-                self.name
-            ),
-        }
+            _ => (self.param_type.struct_type(), self.name.clone()),
+        };
+        format!(
+            r#"{}
+            pub fn {}(mut self, {}: {}) -> Self {{
+                self.{} = Some({});
+                self
+            }}"#,
+            self.doc_setter(&arg_type),
+            self.name,
+            self.name,
+            arg_type,
+            self.name,
+            value
+        )
+    }
+
+    fn set_argument(&self, value: &str) -> String {
+        let (setter, value) = match self.param_type {
+            ParamType::Int { .. } => ("set_int", value.to_string()),
+            ParamType::UInt { .. } => ("set_uint64", value.to_string()),
+            ParamType::Double { .. } => ("set_double", value.to_string()),
+            ParamType::Bool { .. } => ("set_bool", value.to_string()),
+            ParamType::Enum { .. } => ("set_int", format!("{} as i32", value)),
+            ParamType::Str => ("set_string", value.to_string()),
+            ParamType::ArrayInt => ("set_ints", value.to_string()),
+            ParamType::ArrayDouble => ("set_doubles", value.to_string()),
+            ParamType::ArrayImage => ("set_images", value.to_string()),
+            ParamType::ArrayByte | ParamType::VipsBlob => ("set_blob", value.to_string()),
+            ParamType::VipsImage { .. } => ("set_image", value.to_string()),
+            ParamType::VipsInterpolate => ("set_interpolate", value.to_string()),
+            ParamType::VipsSource => ("set_source", value.to_string()),
+            ParamType::VipsTarget => ("set_target", value.to_string()),
+        };
+        format!("operation.{}(\"{}\", {})?;", setter, self.vips_name, value)
     }
 
-    fn declare_opt_name(&self) -> String {
+    fn set_option(&self, opt_name: &str) -> String {
+        let borrow = match self.param_type {
+            ParamType::Int { .. }
+            | ParamType::UInt { .. }
+            | ParamType::Double { .. }
+            | ParamType::Bool { .. }
+            | ParamType::Enum { .. } => "",
+            _ => "&",
+        };
         format!(
-            "let {}_in_name = utils::new_c_string(\"{}\")?;",
-            self.name, self.vips_name
+            "if let Some({}) = {}{}.{} {{ {} }}",
+            self.name,
+            borrow,
+            opt_name,
+            self.name,
+            self.set_argument(&self.name)
         )
     }
 
-    fn opt_param_pair(&self) -> String {
-        let init_var = match self.param_type {
-            ParamType::Str => format!("{}_in.as_ref().map(|s| s.as_ptr()).unwrap_or(std::ptr::null::<c_char>())", self.name),
-            _ => format!("{}_in", self.name),
+    fn get_output(&self) -> String {
+        let getter = match self.param_type {
+            ParamType::Int { .. } => "get_int",
+            ParamType::UInt { .. } => "get_uint64",
+            ParamType::Double { .. } => "get_double",
+            ParamType::Bool { .. } => "get_bool",
+            ParamType::ArrayInt => "get_ints",
+            ParamType::ArrayDouble => "get_doubles",
+            ParamType::ArrayByte | ParamType::VipsBlob => "get_blob",
+            ParamType::VipsImage { .. } => "get_image",
+            _ => panic!("Unsupported output: {}", self.name),
+        };
+        format!("operation.{}(\"{}\")?", getter, self.vips_name)
+    }
+
+    fn get_optional_output(&self) -> String {
+        let getter = match self.param_type {
+            ParamType::Str => "get_string",
+            ParamType::VipsImage { .. } => "get_optional_image",
+            ParamType::Enum { .. } => "get_int",
+            _ => return self.get_output(),
         };
-        format!("{}_in_name.as_ptr(), {}", self.name, init_var)
+        format!("operation.{}(\"{}\")?", getter, self.vips_name)
+    }
+
+    fn doc_output(&self, type_str: &str) -> String {
+        let main_doc = format!("/// {}: `{}` -> {}", self.name, type_str, self.description);
+        match self.param_type {
+            ParamType::Enum { .. } => format!("{}\n{}", main_doc, self.param_type.doc()),
+            _ => main_doc,
+        }
+    }
+
+    fn optional_out_type(&self) -> String {
+        match self.param_type {
+            ParamType::Str => String::from("Option<String>"),
+            ParamType::VipsImage { .. } => String::from("Option<VipsImage>"),
+            // flags can combine several values, so they are returned as the raw bits
+            ParamType::Enum { .. } => String::from("i32"),
+            _ => self.param_type.struct_type(),
+        }
     }
 
     fn declare_out_variable(&self) -> String {
@@ -584,21 +791,8 @@ impl Parameter {
         }
     }
 
-    fn default(&self) -> String {
-        match self.param_type {
-            ParamType::Str if self.description.contains("ICC") => {
-                format!("{}: Some(String::from(\"sRGB\"))", self.name)
-            }
-            ParamType::Str => format!("{}: None", self.name),
-            _ => format!("{}: {}", self.name, self.param_type.default()),
-        }
-    }
-
     fn struct_declaration(&self) -> String {
-        match self.param_type {
-            ParamType::Str => format!("{}: Option<String>", self.name),
-            _ => format!("{}: {}", self.name, self.param_type.struct_type()),
-        }
+        format!("pub {}: Option<{}>", self.name, self.param_type.struct_type())
     }
 
     fn param_declaration(&self) -> String {
@@ -641,6 +835,7 @@ enum ParamType {
     VipsTarget,
     VipsImage {
         prev: Option<String>,
+        modify: bool,
     },
     VipsBlob,
 }
@@ -707,6 +902,7 @@ impl ParamType {
             ParamType::ArrayByte => String::from("&[u8]"),
             ParamType::ArrayImage => String::from("&mut [VipsImage]"),
             ParamType::VipsInterpolate => String::from("&VipsInterpolate"),
+            ParamType::VipsImage { modify: true, .. } => String::from("&mut VipsImage"),
             ParamType::VipsImage { .. } => String::from("&VipsImage"),
             ParamType::VipsSource => String::from("&VipsSource"),
             ParamType::VipsTarget => String::from("&VipsTarget"),
@@ -724,35 +920,17 @@ impl ParamType {
         }
     }
 
-    fn vips_in_type(&self, is_optional: bool) -> String {
+    fn vips_in_type(&self) -> String {
         match self {
             ParamType::Int { .. } => String::from("i32"),
             ParamType::UInt { .. } => String::from("u64"),
             ParamType::Double { .. } => String::from("f64"),
             ParamType::Str => String::from("CString"),
             ParamType::Bool { .. } => String::from("i32"),
-            ParamType::ArrayInt => {
-                if !is_optional {
-                    String::from("*mut i32")
-                } else {
-                    String::from("utils::VipsArrayIntWrapper")
-                }
-            }
-            ParamType::ArrayDouble => {
-                if !is_optional {
-                    String::from("*mut f64")
-                } else {
-                    String::from("utils::VipsArrayDoubleWrapper")
-                }
-            }
+            ParamType::ArrayInt => String::from("*mut i32"),
+            ParamType::ArrayDouble => String::from("*mut f64"),
             ParamType::ArrayByte => String::from("*mut c_void"),
-            ParamType::ArrayImage => {
-                if !is_optional {
-                    String::from("*mut *mut bindings::VipsImage")
-                } else {
-                    String::from("utils::VipsArrayImageWrapper")
-                }
-            }
+            ParamType::ArrayImage => String::from("*mut *mut bindings::VipsImage"),
             ParamType::VipsInterpolate => String::from("*mut bindings::VipsInterpolate"),
             ParamType::VipsImage { .. } => String::from("*mut bindings::VipsImage"),
             ParamType::VipsSource => String::from("*mut bindings::VipsSource"),
@@ -896,12 +1074,20 @@ fn split_flags(output: &[u8]) -> Vec<String> {
 }
 
 fn parse_param(param_list: Vec<&str>, order: u8, prev: Option<String>) -> (bool, Parameter) {
-    let (mut param_name, is_output) = if param_list[0].starts_with("OUTPUT:") {
+    let (param_name, is_output) = if param_list[0].starts_with("OUTPUT:") {
         let splited: Vec<&str> = param_list[0].split("OUTPUT:").collect();
         (String::from(splited[1]), true)
     } else {
         (String::from(param_list[0]), false)
     };
+    // images modified in place (the draw operations) must be borrowed mutably
+    let (mut param_name, modify) = if param_name.starts_with("MODIFY:") {
+        let splited: Vec<&str> = param_name.split("MODIFY:").collect();
+        (String::from(splited[1]), true)
+    } else {
+        (param_name, false)
+    };
+    let vips_name = param_name.clone();
     if ["in", "ref"].contains(&param_name.as_str()) {
         param_name = format!("{}p", param_name);
     }
@@ -910,7 +1096,7 @@ fn parse_param(param_list: Vec<&str>, order: u8, prev: Option<String>) -> (bool,
     let param_type = if param_list[3].starts_with("string") {
         ParamType::Str
     } else if param_list[3].starts_with("VipsImage") {
-        ParamType::VipsImage { prev }
+        ParamType::VipsImage { prev, modify }
     } else if param_list[3].starts_with("VipsBlob") {
         ParamType::VipsBlob
     } else if param_list[3].starts_with("VipsInterpolate") {
@@ -987,7 +1173,7 @@ fn parse_param(param_list: Vec<&str>, order: u8, prev: Option<String>) -> (bool,
         Parameter {
             order,
             name: param_name.to_snake_case(),
-            vips_name: param_name.to_string(),
+            vips_name,
             nick: nick.to_upper_camel_case(),
             description: description.to_string(),
             param_type,
@@ -1003,6 +1189,7 @@ fn parse_output(output: String) -> Vec<Operation> {
             let mut required: Vec<Parameter> = Vec::new();
             let mut optional: Vec<Parameter> = Vec::new();
             let mut output: Vec<Parameter> = Vec::new();
+            let mut optional_output: Vec<Parameter> = Vec::new();
 
             let mut op_iter = op_str.lines().filter(|op| !op.is_empty());
 
@@ -1107,8 +1294,12 @@ fn parse_output(output: String) -> Vec<Operation> {
                     .take_while(|line| *line != "PARAM:")
                     .collect();
 
-                let (_, param) = parse_param(param_list, 0, None);
-                optional.push(param);
+                let (is_output, param) = parse_param(param_list, 0, None);
+                if is_output {
+                    optional_output.push(param);
+                } else {
+                    optional.push(param);
+                }
             }
             Operation {
                 name: if name_split[0] == "match" {
@@ -1122,6 +1313,7 @@ fn parse_output(output: String) -> Vec<Operation> {
                 required,
                 optional,
                 output,
+                optional_output,
             }
         })
         .collect()
@@ -1193,7 +1385,6 @@ fn rustfmt_generated_strin(source: &str) -> io::Result<String> {
 
 fn main() {
     let operation_blacklist = [
-        "VipsForeignSaveDzBuffer",
         "crop",
         "VipsLinear",
         "VipsGetpoint",
@@ -1283,23 +1474,32 @@ fn main() {
             .collect::<Vec<_>>()
     );
 
-    let (methods, errors, errors_display) = operations
+    let (methods, errors, errors_display, errors_nickname) = operations
         .iter()
         .filter(|o| !operation_blacklist.contains(&o.vips_operation.as_str()))
         .fold(
-            (String::new(), String::new(), String::new()),
-            |(mut methods, mut errors, mut errors_display), operation| {
+            (String::new(), String::new(), String::new(), String::new()),
+            |(mut methods, mut errors, mut errors_display, mut errors_nickname), operation| {
                 methods.push_str(operation.body().as_str());
                 errors.push_str(format!("{}Error,\n", operation.name.to_upper_camel_case()).as_str());
                 errors_display.push_str(
                     format!(
-                        "Error::{}Error => write!(f, \"vips error: {}Error. Check error buffer for more details\"),\n",
+                        "Error::{}Error => write!(f, \"vips error: {}Error - {} failed\"),\n",
+                        operation.name.to_upper_camel_case(),
+                        operation.name.to_upper_camel_case(),
+                        operation.vips_name
+                    )
+                    .as_str(),
+                );
+                errors_nickname.push_str(
+                    format!(
+                        "Error::{}Error => Some(\"{}\"),\n",
                         operation.name.to_upper_camel_case(),
-                        operation.name.to_upper_camel_case()
+                        operation.vips_name
                     )
                     .as_str(),
                 );
-                (methods, errors, errors_display)
+                (methods, errors, errors_display, errors_nickname)
             },
         );
 
@@ -1341,11 +1541,45 @@ fn main() {
 
     let errors_content = format!(
         r#"
+    /// What libvips reported for a failed call, drained from its error buffer right after the call failed
+    #[derive(Debug, Clone, Default, PartialEq)]
+    pub struct ErrorDetails {{
+        /// message written by libvips to the error buffer
+        pub message: String,
+        /// nickname of the operation that failed, if known
+        pub operation: Option<String>,
+        /// filename of the input image, if known
+        pub filename: Option<String>,
+    }}
+
     #[derive(Debug)]
     pub enum Error {{
         InitializationError(&'static str),
         OperationError(&'static str),
         IOError(&'static str),
+        VipsError {{
+            kind: Box<Error>,
+            details: ErrorDetails,
+        }},
+        MissingFieldError(String),
+        FieldTypeError {{
+            name: String,
+            expected: &'static str,
+            found: String,
+        }},
+        Cancelled,
+        MissingArgumentError(String),
+        ArgumentTypeError {{
+            name: String,
+            expected: &'static str,
+            found: String,
+        }},
+        Blocked,
+        LimitExceeded {{
+            limit: &'static str,
+            value: u64,
+            max: u64,
+        }},
         LinearError,
         CaseError,
         GetpointError,
@@ -1358,18 +1592,65 @@ fn main() {
                 Error::InitializationError(msg) => write!(f, "vips error: InitializationError - {{}}", msg),
                 Error::OperationError(msg) => write!(f, "vips error: OperationError - {{}}", msg),
                 Error::IOError(msg) => write!(f, "vips error: IOError - {{}}", msg),
-                Error::LinearError => write!(f, "vips error: LinearError. Check error buffer for more details"),
-                Error::CaseError => write!(f, "vips error: CaseError. Check error buffer for more details"),
-                Error::GetpointError => write!(f, "vips error: GetpointError. Check error buffer for more details"),
+                Error::VipsError {{ kind, details }} => match &details.filename {{
+                    Some(filename) => write!(f, "vips error: {{:?}} - {{}} ({{}})", kind, details.message.trim_end(), filename),
+                    None => write!(f, "vips error: {{:?}} - {{}}", kind, details.message.trim_end()),
+                }},
+                Error::MissingFieldError(name) => write!(f, "vips error: MissingFieldError - image has no field {{}}", name),
+                Error::FieldTypeError {{ name, expected, found }} => write!(f, "vips error: FieldTypeError - field {{}} is of type {{}}, not {{}}", name, found, expected),
+                Error::Cancelled => write!(f, "vips error: Cancelled - evaluation was cancelled"),
+                Error::MissingArgumentError(name) => write!(f, "vips error: MissingArgumentError - operation has no argument {{}}", name),
+                Error::ArgumentTypeError {{ name, expected, found }} => write!(f, "vips error: ArgumentTypeError - argument {{}} is of type {{}}, not {{}}", name, found, expected),
+                Error::Blocked => write!(f, "vips error: Blocked - operation is blocked"),
+                Error::LimitExceeded {{ limit, value, max }} => write!(f, "vips error: LimitExceeded - {{}} of {{}} exceeds the maximum of {{}}", limit, value, max),
+                Error::LinearError => write!(f, "vips error: LinearError - linear failed"),
+                Error::CaseError => write!(f, "vips error: CaseError - case failed"),
+                Error::GetpointError => write!(f, "vips error: GetpointError - getpoint failed"),
                 {}
             }}
         }}
     }}
 
-    impl std::error::Error for Error {{}}
+    impl Error {{
+        /// The error reported by the failing wrapper, without the libvips details
+        pub fn kind(&self) -> &Error {{
+            match self {{
+                Error::VipsError {{ kind, .. }} => kind,
+                _ => self,
+            }}
+        }}
+
+        /// What libvips reported when the call failed
+        pub fn details(&self) -> Option<&ErrorDetails> {{
+            match self {{
+                Error::VipsError {{ details, .. }} => Some(details),
+                _ => None,
+            }}
+        }}
+
+        /// Nickname of the libvips operation reporting this error
+        pub fn operation_nickname(&self) -> Option<&'static str> {{
+            match self.kind() {{
+                Error::LinearError => Some("linear"),
+                Error::CaseError => Some("case"),
+                Error::GetpointError => Some("getpoint"),
+                {}
+                _ => None,
+            }}
+        }}
+    }}
+
+    impl std::error::Error for Error {{
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {{
+            match self {{
+                Error::VipsError {{ kind, .. }} => Some(kind.as_ref()),
+                _ => None,
+            }}
+        }}
+    }}
 
     "#,
-        errors, errors_display
+        errors, errors_display, errors_nickname
     );
 
     let errors_formated = if let Ok(formated) = rustfmt_generated_strin(&errors_content) {
@@ -131,6 +131,8 @@ static void *
             printf("PARAM:\n");
             if (!(argument_class->flags & VIPS_ARGUMENT_INPUT))
                 printf("OUTPUT:");
+            if (argument_class->flags & VIPS_ARGUMENT_MODIFY)
+                printf("MODIFY:");
             show_argument(pspec, argument_class);
         }
 
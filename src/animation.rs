@@ -0,0 +1,227 @@
+// (c) Copyright 2019-2026 OLX
+use crate::error::Error;
+use crate::ops;
+use crate::Result;
+use crate::{VipsImage, VipsSource};
+
+/// delay given to the frames of an image without a `delay` field, in milliseconds
+const DEFAULT_DELAY: i32 = 100;
+
+/// The frames of a multi page image, like an animated GIF, WebP or HEIF. libvips loads them as
+/// one tall image holding the frames one above the other, with their height in `page-height`,
+/// their delays in milliseconds in `delay` and the number of loops in `loop`. An `Animation`
+/// splits such an image in frames and joins them back with these fields set, ready for `gifsave`
+/// or `webpsave`.
+#[derive(Clone, Debug)]
+pub struct Animation {
+    frames: Vec<VipsImage>,
+    delays: Vec<i32>,
+    loop_count: i32,
+}
+
+impl Animation {
+    /// loads all the pages of the file. the loader must support the `n` option. the filename can
+    /// carry other load options, e.g. `"anim.webp[access=sequential]"`, or its own `n`
+    pub fn new_from_file(filename: &str) -> Result<Animation> {
+        Animation::from_image(&VipsImage::new_from_file(&all_pages(filename))?)
+    }
+
+    pub fn new_from_buffer(buffer: &[u8]) -> Result<Animation> {
+        Animation::from_image(
+            &VipsImage::new_from_buffer(
+                buffer,
+                "n=-1",
+            )?,
+        )
+    }
+
+    pub fn new_from_source(source: &VipsSource) -> Result<Animation> {
+        Animation::from_image(
+            &VipsImage::new_from_source(
+                source,
+                "n=-1",
+            )?,
+        )
+    }
+
+    /// splits an image loaded with `n=-1` in frames. the frames are views on the image, no pixel
+    /// is copied
+    pub fn from_image(image: &VipsImage) -> Result<Animation> {
+        let width = image.get_width();
+        let page_height = image.get_page_height();
+        let n_frames = image.get_height() / page_height;
+        let frames = (0..n_frames)
+            .map(|i| {
+                ops::extract_area(
+                    image,
+                    0,
+                    i * page_height,
+                    width,
+                    page_height,
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let mut delays = image
+            .get_array_int("delay")
+            .unwrap_or_default();
+        delays.resize(
+            frames.len(),
+            DEFAULT_DELAY,
+        );
+        let loop_count = image
+            .get_int("loop")
+            .unwrap_or(0);
+        Ok(
+            Animation {
+                frames,
+                delays,
+                loop_count,
+            },
+        )
+    }
+
+    /// an animation of the given frames, all of the same size, each shown for its delay in
+    /// milliseconds. a `loop_count` of 0 loops forever
+    pub fn from_frames(
+        frames: Vec<VipsImage>,
+        delays: Vec<i32>,
+        loop_count: i32,
+    ) -> Result<Animation> {
+        if frames.is_empty() {
+            return Err(Error::OperationError("Animation:from_frames - No frames given"));
+        }
+        if frames.len() != delays.len() {
+            return Err(
+                Error::OperationError("Animation:from_frames - There must be a delay per frame"),
+            );
+        }
+        Ok(
+            Animation {
+                frames,
+                delays,
+                loop_count,
+            },
+        )
+    }
+
+    pub fn frames(&self) -> &[VipsImage] {
+        &self.frames
+    }
+
+    pub fn delays(&self) -> &[i32] {
+        &self.delays
+    }
+
+    pub fn set_delays(&mut self, delays: &[i32]) -> Result<()> {
+        if delays.len()
+            != self
+                .frames
+                .len()
+        {
+            return Err(
+                Error::OperationError("Animation:set_delays - There must be a delay per frame"),
+            );
+        }
+        self.delays = delays.to_vec();
+        Ok(())
+    }
+
+    pub fn loop_count(&self) -> i32 {
+        self.loop_count
+    }
+
+    pub fn set_loop_count(&mut self, loop_count: i32) {
+        self.loop_count = loop_count;
+    }
+
+    /// applies an operation to every frame, e.g. a resize, a crop or a watermark, keeping the
+    /// delays and the loop count. all the frames must keep the same size
+    pub fn map<F>(&self, mut f: F) -> Result<Animation>
+    where
+        F: FnMut(&VipsImage) -> Result<VipsImage>,
+    {
+        let frames = self
+            .frames
+            .iter()
+            .map(&mut f)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(
+            Animation {
+                frames,
+                delays: self
+                    .delays
+                    .clone(),
+                loop_count: self.loop_count,
+            },
+        )
+    }
+
+    /// joins the frames back in one tall image with `page-height`, `delay` and `loop` set, as the
+    /// savers of animated formats expect it
+    pub fn to_image(&self) -> Result<VipsImage> {
+        let width = self.frames[0].get_width();
+        let height = self.frames[0].get_height();
+        if self
+            .frames
+            .iter()
+            .any(|frame| frame.get_width() != width || frame.get_height() != height)
+        {
+            return Err(Error::OperationError("Animation:to_image - Frames have different sizes"));
+        }
+        let mut frames = self
+            .frames
+            .clone();
+        let joined = ops::arrayjoin_with_opts(
+            &mut frames,
+            &ops::ArrayjoinOptions::new().across(1),
+        )?;
+        // the joined image can come from the operation cache and be shared with other callers, so
+        // the fields are set on a copy of its own
+        let mut image = joined.private_copy()?;
+        image.set_int(
+            "page-height",
+            height,
+        )?;
+        image.set_array_int(
+            "delay",
+            &self.delays,
+        )?;
+        image.set_int(
+            "loop",
+            self.loop_count,
+        )?;
+        Ok(image)
+    }
+}
+
+// adds `n=-1` to the load options of the filename, unless they set `n` already
+fn all_pages(filename: &str) -> String {
+    match filename
+        .strip_suffix(']')
+        .and_then(|filename| filename.rsplit_once('['))
+    {
+        Some((_, options))
+            if options
+                .split(',')
+                .any(|option| {
+                    option
+                        .trim()
+                        .starts_with("n=")
+                }) =>
+        {
+            filename.to_string()
+        }
+        Some((name, "")) => format!(
+            "{}[n=-1]",
+            name
+        ),
+        Some((name, options)) => format!(
+            "{}[{},n=-1]",
+            name, options
+        ),
+        None => format!(
+            "{}[n=-1]",
+            filename
+        ),
+    }
+}
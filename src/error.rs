@@ -1,9 +1,43 @@
 
+/// What libvips reported for a failed call, drained from its error buffer right after the call failed
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ErrorDetails {
+    /// message written by libvips to the error buffer
+    pub message: String,
+    /// nickname of the operation that failed, if known
+    pub operation: Option<String>,
+    /// filename of the input image, if known
+    pub filename: Option<String>,
+}
+
 #[derive(Debug)]
 pub enum Error {
     InitializationError(&'static str),
     OperationError(&'static str),
     IOError(&'static str),
+    VipsError {
+        kind: Box<Error>,
+        details: ErrorDetails,
+    },
+    MissingFieldError(String),
+    FieldTypeError {
+        name: String,
+        expected: &'static str,
+        found: String,
+    },
+    Cancelled,
+    MissingArgumentError(String),
+    ArgumentTypeError {
+        name: String,
+        expected: &'static str,
+        found: String,
+    },
+    Blocked,
+    LimitExceeded {
+        limit: &'static str,
+        value: u64,
+        max: u64,
+    },
     LinearError,
     CaseError,
     GetpointError,
@@ -135,12 +169,22 @@ pub enum Error {
     RadloadSourceError,
     SvgloadError,
     SvgloadBufferError,
+    Jp2kloadError,
+    Jp2kloadBufferError,
+    Jp2kloadSourceError,
+    JxlloadError,
+    JxlloadBufferError,
+    JxlloadSourceError,
+    PdfloadError,
+    PdfloadBufferError,
+    PdfloadSourceError,
     GifloadError,
     GifloadBufferError,
     GifloadSourceError,
     PngloadError,
     PngloadBufferError,
     PngloadSourceError,
+    MatloadError,
     JpegloadError,
     JpegloadBufferError,
     WebploadError,
@@ -149,9 +193,18 @@ pub enum Error {
     TiffloadError,
     TiffloadBufferError,
     TiffloadSourceError,
+    FitsloadError,
+    FitsloadSourceError,
+    OpenslideloadError,
+    OpenslideloadSourceError,
+    MagickloadError,
+    MagickloadBufferError,
+    MagickloadSourceError,
     HeifloadError,
     HeifloadBufferError,
     HeifloadSourceError,
+    NiftiloadError,
+    NiftiloadSourceError,
     CsvsaveError,
     CsvsaveTargetError,
     MatrixsaveError,
@@ -167,6 +220,9 @@ pub enum Error {
     RadsaveError,
     RadsaveBufferError,
     RadsaveTargetError,
+    DzsaveError,
+    DzsaveBufferError,
+    DzsaveTargetError,
     GifsaveError,
     GifsaveBufferError,
     GifsaveTargetError,
@@ -184,9 +240,19 @@ pub enum Error {
     TiffsaveError,
     TiffsaveBufferError,
     TiffsaveTargetError,
+    Jp2ksaveError,
+    Jp2ksaveBufferError,
+    Jp2ksaveTargetError,
+    FitssaveError,
+    MagicksaveError,
+    MagicksaveBufferError,
     HeifsaveError,
     HeifsaveBufferError,
     HeifsaveTargetError,
+    JxlsaveError,
+    JxlsaveBufferError,
+    JxlsaveTargetError,
+    NiftisaveError,
     ThumbnailError,
     ThumbnailBufferError,
     ThumbnailImageError,
@@ -303,1164 +369,970 @@ impl std::fmt::Display for Error {
             }
             Error::OperationError(msg) => write!(f, "vips error: OperationError - {}", msg),
             Error::IOError(msg) => write!(f, "vips error: IOError - {}", msg),
-            Error::LinearError => write!(
-                f,
-                "vips error: LinearError. Check error buffer for more details"
-            ),
-            Error::CaseError => write!(
-                f,
-                "vips error: CaseError. Check error buffer for more details"
-            ),
-            Error::GetpointError => write!(
-                f,
-                "vips error: GetpointError. Check error buffer for more details"
-            ),
-            Error::SystemError => write!(
-                f,
-                "vips error: SystemError. Check error buffer for more details"
-            ),
-            Error::AddError => write!(
-                f,
-                "vips error: AddError. Check error buffer for more details"
-            ),
-            Error::MinpairError => write!(
-                f,
-                "vips error: MinpairError. Check error buffer for more details"
-            ),
-            Error::MaxpairError => write!(
-                f,
-                "vips error: MaxpairError. Check error buffer for more details"
-            ),
-            Error::SubtractError => write!(
-                f,
-                "vips error: SubtractError. Check error buffer for more details"
-            ),
-            Error::MultiplyError => write!(
-                f,
-                "vips error: MultiplyError. Check error buffer for more details"
-            ),
-            Error::DivideError => write!(
-                f,
-                "vips error: DivideError. Check error buffer for more details"
-            ),
-            Error::RelationalError => write!(
-                f,
-                "vips error: RelationalError. Check error buffer for more details"
-            ),
-            Error::RemainderError => write!(
-                f,
-                "vips error: RemainderError. Check error buffer for more details"
-            ),
-            Error::BooleanError => write!(
-                f,
-                "vips error: BooleanError. Check error buffer for more details"
-            ),
-            Error::Math2Error => write!(
-                f,
-                "vips error: Math2Error. Check error buffer for more details"
-            ),
-            Error::Complex2Error => write!(
-                f,
-                "vips error: Complex2Error. Check error buffer for more details"
-            ),
-            Error::ComplexformError => write!(
-                f,
-                "vips error: ComplexformError. Check error buffer for more details"
-            ),
-            Error::ClampError => write!(
-                f,
-                "vips error: ClampError. Check error buffer for more details"
-            ),
-            Error::InvertError => write!(
-                f,
-                "vips error: InvertError. Check error buffer for more details"
-            ),
-            Error::MathError => write!(
-                f,
-                "vips error: MathError. Check error buffer for more details"
-            ),
-            Error::AbsError => write!(
-                f,
-                "vips error: AbsError. Check error buffer for more details"
-            ),
-            Error::SignError => write!(
-                f,
-                "vips error: SignError. Check error buffer for more details"
-            ),
-            Error::RoundError => write!(
-                f,
-                "vips error: RoundError. Check error buffer for more details"
-            ),
+            Error::VipsError { kind, details } => match &details.filename {
+                Some(filename) => write!(
+                    f,
+                    "vips error: {:?} - {} ({})",
+                    kind,
+                    details.message.trim_end(),
+                    filename
+                ),
+                None => write!(f, "vips error: {:?} - {}", kind, details.message.trim_end()),
+            },
+            Error::MissingFieldError(name) => {
+                write!(
+                    f,
+                    "vips error: MissingFieldError - image has no field {}",
+                    name
+                )
+            }
+            Error::FieldTypeError {
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "vips error: FieldTypeError - field {} is of type {}, not {}",
+                name, found, expected
+            ),
+            Error::Cancelled => write!(f, "vips error: Cancelled - evaluation was cancelled"),
+            Error::MissingArgumentError(name) => write!(
+                f,
+                "vips error: MissingArgumentError - operation has no argument {}",
+                name
+            ),
+            Error::ArgumentTypeError {
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "vips error: ArgumentTypeError - argument {} is of type {}, not {}",
+                name, found, expected
+            ),
+            Error::Blocked => write!(f, "vips error: Blocked - operation is blocked"),
+            Error::LimitExceeded { limit, value, max } => write!(
+                f,
+                "vips error: LimitExceeded - {} of {} exceeds the maximum of {}",
+                limit, value, max
+            ),
+            Error::LinearError => write!(f, "vips error: LinearError - linear failed"),
+            Error::CaseError => write!(f, "vips error: CaseError - case failed"),
+            Error::GetpointError => write!(f, "vips error: GetpointError - getpoint failed"),
+            Error::SystemError => write!(f, "vips error: SystemError - system failed"),
+            Error::AddError => write!(f, "vips error: AddError - add failed"),
+            Error::MinpairError => write!(f, "vips error: MinpairError - minpair failed"),
+            Error::MaxpairError => write!(f, "vips error: MaxpairError - maxpair failed"),
+            Error::SubtractError => write!(f, "vips error: SubtractError - subtract failed"),
+            Error::MultiplyError => write!(f, "vips error: MultiplyError - multiply failed"),
+            Error::DivideError => write!(f, "vips error: DivideError - divide failed"),
+            Error::RelationalError => write!(f, "vips error: RelationalError - relational failed"),
+            Error::RemainderError => write!(f, "vips error: RemainderError - remainder failed"),
+            Error::BooleanError => write!(f, "vips error: BooleanError - boolean failed"),
+            Error::Math2Error => write!(f, "vips error: Math2Error - math2 failed"),
+            Error::Complex2Error => write!(f, "vips error: Complex2Error - complex2 failed"),
+            Error::ComplexformError => {
+                write!(f, "vips error: ComplexformError - complexform failed")
+            }
+            Error::ClampError => write!(f, "vips error: ClampError - clamp failed"),
+            Error::InvertError => write!(f, "vips error: InvertError - invert failed"),
+            Error::MathError => write!(f, "vips error: MathError - math failed"),
+            Error::AbsError => write!(f, "vips error: AbsError - abs failed"),
+            Error::SignError => write!(f, "vips error: SignError - sign failed"),
+            Error::RoundError => write!(f, "vips error: RoundError - round failed"),
             Error::RelationalConstError => write!(
                 f,
-                "vips error: RelationalConstError. Check error buffer for more details"
+                "vips error: RelationalConstError - relational_const failed"
             ),
             Error::RemainderConstError => write!(
                 f,
-                "vips error: RemainderConstError. Check error buffer for more details"
-            ),
-            Error::BooleanConstError => write!(
-                f,
-                "vips error: BooleanConstError. Check error buffer for more details"
-            ),
-            Error::Math2ConstError => write!(
-                f,
-                "vips error: Math2ConstError. Check error buffer for more details"
-            ),
-            Error::ComplexError => write!(
-                f,
-                "vips error: ComplexError. Check error buffer for more details"
-            ),
-            Error::ComplexgetError => write!(
-                f,
-                "vips error: ComplexgetError. Check error buffer for more details"
-            ),
-            Error::SumError => write!(
-                f,
-                "vips error: SumError. Check error buffer for more details"
-            ),
-            Error::AvgError => write!(
-                f,
-                "vips error: AvgError. Check error buffer for more details"
-            ),
-            Error::MinError => write!(
-                f,
-                "vips error: MinError. Check error buffer for more details"
-            ),
-            Error::MaxError => write!(
-                f,
-                "vips error: MaxError. Check error buffer for more details"
-            ),
-            Error::DeviateError => write!(
-                f,
-                "vips error: DeviateError. Check error buffer for more details"
-            ),
-            Error::StatsError => write!(
-                f,
-                "vips error: StatsError. Check error buffer for more details"
-            ),
-            Error::HistFindError => write!(
-                f,
-                "vips error: HistFindError. Check error buffer for more details"
-            ),
-            Error::HistFindNdimError => write!(
-                f,
-                "vips error: HistFindNdimError. Check error buffer for more details"
+                "vips error: RemainderConstError - remainder_const failed"
             ),
+            Error::BooleanConstError => {
+                write!(f, "vips error: BooleanConstError - boolean_const failed")
+            }
+            Error::Math2ConstError => write!(f, "vips error: Math2ConstError - math2_const failed"),
+            Error::ComplexError => write!(f, "vips error: ComplexError - complex failed"),
+            Error::ComplexgetError => write!(f, "vips error: ComplexgetError - complexget failed"),
+            Error::SumError => write!(f, "vips error: SumError - sum failed"),
+            Error::AvgError => write!(f, "vips error: AvgError - avg failed"),
+            Error::MinError => write!(f, "vips error: MinError - min failed"),
+            Error::MaxError => write!(f, "vips error: MaxError - max failed"),
+            Error::DeviateError => write!(f, "vips error: DeviateError - deviate failed"),
+            Error::StatsError => write!(f, "vips error: StatsError - stats failed"),
+            Error::HistFindError => write!(f, "vips error: HistFindError - hist_find failed"),
+            Error::HistFindNdimError => {
+                write!(f, "vips error: HistFindNdimError - hist_find_ndim failed")
+            }
             Error::HistFindIndexedError => write!(
                 f,
-                "vips error: HistFindIndexedError. Check error buffer for more details"
-            ),
-            Error::HoughLineError => write!(
-                f,
-                "vips error: HoughLineError. Check error buffer for more details"
-            ),
-            Error::HoughCircleError => write!(
-                f,
-                "vips error: HoughCircleError. Check error buffer for more details"
-            ),
-            Error::ProjectError => write!(
-                f,
-                "vips error: ProjectError. Check error buffer for more details"
-            ),
-            Error::ProfileError => write!(
-                f,
-                "vips error: ProfileError. Check error buffer for more details"
-            ),
-            Error::MeasureError => write!(
-                f,
-                "vips error: MeasureError. Check error buffer for more details"
-            ),
-            Error::FindTrimError => write!(
-                f,
-                "vips error: FindTrimError. Check error buffer for more details"
-            ),
-            Error::CopyError => write!(
-                f,
-                "vips error: CopyError. Check error buffer for more details"
-            ),
-            Error::TilecacheError => write!(
-                f,
-                "vips error: TilecacheError. Check error buffer for more details"
-            ),
-            Error::LinecacheError => write!(
-                f,
-                "vips error: LinecacheError. Check error buffer for more details"
-            ),
-            Error::SequentialError => write!(
-                f,
-                "vips error: SequentialError. Check error buffer for more details"
-            ),
-            Error::EmbedError => write!(
-                f,
-                "vips error: EmbedError. Check error buffer for more details"
-            ),
-            Error::GravityError => write!(
-                f,
-                "vips error: GravityError. Check error buffer for more details"
-            ),
-            Error::FlipError => write!(
-                f,
-                "vips error: FlipError. Check error buffer for more details"
-            ),
-            Error::InsertError => write!(
-                f,
-                "vips error: InsertError. Check error buffer for more details"
-            ),
-            Error::JoinError => write!(
-                f,
-                "vips error: JoinError. Check error buffer for more details"
-            ),
-            Error::ArrayjoinError => write!(
-                f,
-                "vips error: ArrayjoinError. Check error buffer for more details"
-            ),
-            Error::ExtractAreaError => write!(
-                f,
-                "vips error: ExtractAreaError. Check error buffer for more details"
-            ),
-            Error::SmartcropError => write!(
-                f,
-                "vips error: SmartcropError. Check error buffer for more details"
-            ),
-            Error::ExtractBandError => write!(
-                f,
-                "vips error: ExtractBandError. Check error buffer for more details"
-            ),
-            Error::BandjoinError => write!(
-                f,
-                "vips error: BandjoinError. Check error buffer for more details"
-            ),
-            Error::BandjoinConstError => write!(
-                f,
-                "vips error: BandjoinConstError. Check error buffer for more details"
-            ),
-            Error::BandrankError => write!(
-                f,
-                "vips error: BandrankError. Check error buffer for more details"
-            ),
-            Error::BandmeanError => write!(
-                f,
-                "vips error: BandmeanError. Check error buffer for more details"
-            ),
-            Error::BandboolError => write!(
-                f,
-                "vips error: BandboolError. Check error buffer for more details"
-            ),
-            Error::ReplicateError => write!(
-                f,
-                "vips error: ReplicateError. Check error buffer for more details"
-            ),
-            Error::CastError => write!(
-                f,
-                "vips error: CastError. Check error buffer for more details"
-            ),
-            Error::RotError => write!(
-                f,
-                "vips error: RotError. Check error buffer for more details"
+                "vips error: HistFindIndexedError - hist_find_indexed failed"
             ),
-            Error::Rot45Error => write!(
-                f,
-                "vips error: Rot45Error. Check error buffer for more details"
-            ),
-            Error::AutorotError => write!(
-                f,
-                "vips error: AutorotError. Check error buffer for more details"
-            ),
-            Error::IfthenelseError => write!(
-                f,
-                "vips error: IfthenelseError. Check error buffer for more details"
-            ),
-            Error::RecombError => write!(
-                f,
-                "vips error: RecombError. Check error buffer for more details"
-            ),
-            Error::BandfoldError => write!(
-                f,
-                "vips error: BandfoldError. Check error buffer for more details"
-            ),
-            Error::BandunfoldError => write!(
-                f,
-                "vips error: BandunfoldError. Check error buffer for more details"
-            ),
-            Error::FlattenError => write!(
-                f,
-                "vips error: FlattenError. Check error buffer for more details"
-            ),
-            Error::PremultiplyError => write!(
-                f,
-                "vips error: PremultiplyError. Check error buffer for more details"
-            ),
-            Error::UnpremultiplyError => write!(
-                f,
-                "vips error: UnpremultiplyError. Check error buffer for more details"
-            ),
-            Error::GridError => write!(
-                f,
-                "vips error: GridError. Check error buffer for more details"
-            ),
-            Error::Transpose3dError => write!(
-                f,
-                "vips error: Transpose3dError. Check error buffer for more details"
-            ),
-            Error::ScaleError => write!(
-                f,
-                "vips error: ScaleError. Check error buffer for more details"
-            ),
-            Error::WrapError => write!(
-                f,
-                "vips error: WrapError. Check error buffer for more details"
-            ),
-            Error::ZoomError => write!(
-                f,
-                "vips error: ZoomError. Check error buffer for more details"
-            ),
-            Error::SubsampleError => write!(
-                f,
-                "vips error: SubsampleError. Check error buffer for more details"
-            ),
-            Error::MsbError => write!(
-                f,
-                "vips error: MsbError. Check error buffer for more details"
-            ),
-            Error::ByteswapError => write!(
-                f,
-                "vips error: ByteswapError. Check error buffer for more details"
-            ),
-            Error::FalsecolourError => write!(
-                f,
-                "vips error: FalsecolourError. Check error buffer for more details"
-            ),
-            Error::GammaError => write!(
-                f,
-                "vips error: GammaError. Check error buffer for more details"
-            ),
-            Error::CompositeError => write!(
-                f,
-                "vips error: CompositeError. Check error buffer for more details"
-            ),
-            Error::Composite2Error => write!(
-                f,
-                "vips error: Composite2Error. Check error buffer for more details"
-            ),
-            Error::AddalphaError => write!(
+            Error::HoughLineError => write!(f, "vips error: HoughLineError - hough_line failed"),
+            Error::HoughCircleError => {
+                write!(f, "vips error: HoughCircleError - hough_circle failed")
+            }
+            Error::ProjectError => write!(f, "vips error: ProjectError - project failed"),
+            Error::ProfileError => write!(f, "vips error: ProfileError - profile failed"),
+            Error::MeasureError => write!(f, "vips error: MeasureError - measure failed"),
+            Error::FindTrimError => write!(f, "vips error: FindTrimError - find_trim failed"),
+            Error::CopyError => write!(f, "vips error: CopyError - copy failed"),
+            Error::TilecacheError => write!(f, "vips error: TilecacheError - tilecache failed"),
+            Error::LinecacheError => write!(f, "vips error: LinecacheError - linecache failed"),
+            Error::SequentialError => write!(f, "vips error: SequentialError - sequential failed"),
+            Error::EmbedError => write!(f, "vips error: EmbedError - embed failed"),
+            Error::GravityError => write!(f, "vips error: GravityError - gravity failed"),
+            Error::FlipError => write!(f, "vips error: FlipError - flip failed"),
+            Error::InsertError => write!(f, "vips error: InsertError - insert failed"),
+            Error::JoinError => write!(f, "vips error: JoinError - join failed"),
+            Error::ArrayjoinError => write!(f, "vips error: ArrayjoinError - arrayjoin failed"),
+            Error::ExtractAreaError => {
+                write!(f, "vips error: ExtractAreaError - extract_area failed")
+            }
+            Error::SmartcropError => write!(f, "vips error: SmartcropError - smartcrop failed"),
+            Error::ExtractBandError => {
+                write!(f, "vips error: ExtractBandError - extract_band failed")
+            }
+            Error::BandjoinError => write!(f, "vips error: BandjoinError - bandjoin failed"),
+            Error::BandjoinConstError => {
+                write!(f, "vips error: BandjoinConstError - bandjoin_const failed")
+            }
+            Error::BandrankError => write!(f, "vips error: BandrankError - bandrank failed"),
+            Error::BandmeanError => write!(f, "vips error: BandmeanError - bandmean failed"),
+            Error::BandboolError => write!(f, "vips error: BandboolError - bandbool failed"),
+            Error::ReplicateError => write!(f, "vips error: ReplicateError - replicate failed"),
+            Error::CastError => write!(f, "vips error: CastError - cast failed"),
+            Error::RotError => write!(f, "vips error: RotError - rot failed"),
+            Error::Rot45Error => write!(f, "vips error: Rot45Error - rot45 failed"),
+            Error::AutorotError => write!(f, "vips error: AutorotError - autorot failed"),
+            Error::IfthenelseError => write!(f, "vips error: IfthenelseError - ifthenelse failed"),
+            Error::RecombError => write!(f, "vips error: RecombError - recomb failed"),
+            Error::BandfoldError => write!(f, "vips error: BandfoldError - bandfold failed"),
+            Error::BandunfoldError => write!(f, "vips error: BandunfoldError - bandunfold failed"),
+            Error::FlattenError => write!(f, "vips error: FlattenError - flatten failed"),
+            Error::PremultiplyError => {
+                write!(f, "vips error: PremultiplyError - premultiply failed")
+            }
+            Error::UnpremultiplyError => {
+                write!(f, "vips error: UnpremultiplyError - unpremultiply failed")
+            }
+            Error::GridError => write!(f, "vips error: GridError - grid failed"),
+            Error::Transpose3dError => {
+                write!(f, "vips error: Transpose3dError - transpose3d failed")
+            }
+            Error::ScaleError => write!(f, "vips error: ScaleError - scale failed"),
+            Error::WrapError => write!(f, "vips error: WrapError - wrap failed"),
+            Error::ZoomError => write!(f, "vips error: ZoomError - zoom failed"),
+            Error::SubsampleError => write!(f, "vips error: SubsampleError - subsample failed"),
+            Error::MsbError => write!(f, "vips error: MsbError - msb failed"),
+            Error::ByteswapError => write!(f, "vips error: ByteswapError - byteswap failed"),
+            Error::FalsecolourError => {
+                write!(f, "vips error: FalsecolourError - falsecolour failed")
+            }
+            Error::GammaError => write!(f, "vips error: GammaError - gamma failed"),
+            Error::CompositeError => write!(f, "vips error: CompositeError - composite failed"),
+            Error::Composite2Error => write!(f, "vips error: Composite2Error - composite2 failed"),
+            Error::AddalphaError => write!(f, "vips error: AddalphaError - addalpha failed"),
+            Error::BlackError => write!(f, "vips error: BlackError - black failed"),
+            Error::GaussnoiseError => write!(f, "vips error: GaussnoiseError - gaussnoise failed"),
+            Error::XyzError => write!(f, "vips error: XyzError - xyz failed"),
+            Error::GaussmatError => write!(f, "vips error: GaussmatError - gaussmat failed"),
+            Error::LogmatError => write!(f, "vips error: LogmatError - logmat failed"),
+            Error::TextError => write!(f, "vips error: TextError - text failed"),
+            Error::SdfError => write!(f, "vips error: SdfError - sdf failed"),
+            Error::EyeError => write!(f, "vips error: EyeError - eye failed"),
+            Error::GreyError => write!(f, "vips error: GreyError - grey failed"),
+            Error::ZoneError => write!(f, "vips error: ZoneError - zone failed"),
+            Error::SinesError => write!(f, "vips error: SinesError - sines failed"),
+            Error::MaskIdealError => write!(f, "vips error: MaskIdealError - mask_ideal failed"),
+            Error::MaskIdealRingError => {
+                write!(f, "vips error: MaskIdealRingError - mask_ideal_ring failed")
+            }
+            Error::MaskIdealBandError => {
+                write!(f, "vips error: MaskIdealBandError - mask_ideal_band failed")
+            }
+            Error::MaskButterworthError => write!(
                 f,
-                "vips error: AddalphaError. Check error buffer for more details"
+                "vips error: MaskButterworthError - mask_butterworth failed"
             ),
-            Error::BlackError => write!(
+            Error::MaskButterworthRingError => write!(
                 f,
-                "vips error: BlackError. Check error buffer for more details"
+                "vips error: MaskButterworthRingError - mask_butterworth_ring failed"
             ),
-            Error::GaussnoiseError => write!(
+            Error::MaskButterworthBandError => write!(
                 f,
-                "vips error: GaussnoiseError. Check error buffer for more details"
+                "vips error: MaskButterworthBandError - mask_butterworth_band failed"
             ),
-            Error::XyzError => write!(
+            Error::MaskGaussianError => {
+                write!(f, "vips error: MaskGaussianError - mask_gaussian failed")
+            }
+            Error::MaskGaussianRingError => write!(
                 f,
-                "vips error: XyzError. Check error buffer for more details"
+                "vips error: MaskGaussianRingError - mask_gaussian_ring failed"
             ),
-            Error::GaussmatError => write!(
+            Error::MaskGaussianBandError => write!(
                 f,
-                "vips error: GaussmatError. Check error buffer for more details"
+                "vips error: MaskGaussianBandError - mask_gaussian_band failed"
             ),
-            Error::LogmatError => write!(
+            Error::MaskFractalError => {
+                write!(f, "vips error: MaskFractalError - mask_fractal failed")
+            }
+            Error::BuildlutError => write!(f, "vips error: BuildlutError - buildlut failed"),
+            Error::InvertlutError => write!(f, "vips error: InvertlutError - invertlut failed"),
+            Error::TonelutError => write!(f, "vips error: TonelutError - tonelut failed"),
+            Error::IdentityError => write!(f, "vips error: IdentityError - identity failed"),
+            Error::FractsurfError => write!(f, "vips error: FractsurfError - fractsurf failed"),
+            Error::WorleyError => write!(f, "vips error: WorleyError - worley failed"),
+            Error::PerlinError => write!(f, "vips error: PerlinError - perlin failed"),
+            Error::SwitchError => write!(f, "vips error: SwitchError - switch failed"),
+            Error::CsvloadError => write!(f, "vips error: CsvloadError - csvload failed"),
+            Error::CsvloadSourceError => {
+                write!(f, "vips error: CsvloadSourceError - csvload_source failed")
+            }
+            Error::MatrixloadError => write!(f, "vips error: MatrixloadError - matrixload failed"),
+            Error::MatrixloadSourceError => write!(
                 f,
-                "vips error: LogmatError. Check error buffer for more details"
+                "vips error: MatrixloadSourceError - matrixload_source failed"
             ),
-            Error::TextError => write!(
+            Error::RawloadError => write!(f, "vips error: RawloadError - rawload failed"),
+            Error::VipsloadError => write!(f, "vips error: VipsloadError - vipsload failed"),
+            Error::VipsloadSourceError => write!(
                 f,
-                "vips error: TextError. Check error buffer for more details"
+                "vips error: VipsloadSourceError - vipsload_source failed"
             ),
-            Error::SdfError => write!(
+            Error::AnalyzeloadError => {
+                write!(f, "vips error: AnalyzeloadError - analyzeload failed")
+            }
+            Error::PpmloadError => write!(f, "vips error: PpmloadError - ppmload failed"),
+            Error::PpmloadBufferError => {
+                write!(f, "vips error: PpmloadBufferError - ppmload_buffer failed")
+            }
+            Error::PpmloadSourceError => {
+                write!(f, "vips error: PpmloadSourceError - ppmload_source failed")
+            }
+            Error::RadloadError => write!(f, "vips error: RadloadError - radload failed"),
+            Error::RadloadBufferError => {
+                write!(f, "vips error: RadloadBufferError - radload_buffer failed")
+            }
+            Error::RadloadSourceError => {
+                write!(f, "vips error: RadloadSourceError - radload_source failed")
+            }
+            Error::SvgloadError => write!(f, "vips error: SvgloadError - svgload failed"),
+            Error::SvgloadBufferError => {
+                write!(f, "vips error: SvgloadBufferError - svgload_buffer failed")
+            }
+            Error::Jp2kloadError => write!(f, "vips error: Jp2kloadError - jp2kload failed"),
+            Error::Jp2kloadBufferError => write!(
                 f,
-                "vips error: SdfError. Check error buffer for more details"
+                "vips error: Jp2kloadBufferError - jp2kload_buffer failed"
             ),
-            Error::EyeError => write!(
+            Error::Jp2kloadSourceError => write!(
                 f,
-                "vips error: EyeError. Check error buffer for more details"
+                "vips error: Jp2kloadSourceError - jp2kload_source failed"
             ),
-            Error::GreyError => write!(
+            Error::JxlloadError => write!(f, "vips error: JxlloadError - jxlload failed"),
+            Error::JxlloadBufferError => {
+                write!(f, "vips error: JxlloadBufferError - jxlload_buffer failed")
+            }
+            Error::JxlloadSourceError => {
+                write!(f, "vips error: JxlloadSourceError - jxlload_source failed")
+            }
+            Error::PdfloadError => write!(f, "vips error: PdfloadError - pdfload failed"),
+            Error::PdfloadBufferError => {
+                write!(f, "vips error: PdfloadBufferError - pdfload_buffer failed")
+            }
+            Error::PdfloadSourceError => {
+                write!(f, "vips error: PdfloadSourceError - pdfload_source failed")
+            }
+            Error::GifloadError => write!(f, "vips error: GifloadError - gifload failed"),
+            Error::GifloadBufferError => {
+                write!(f, "vips error: GifloadBufferError - gifload_buffer failed")
+            }
+            Error::GifloadSourceError => {
+                write!(f, "vips error: GifloadSourceError - gifload_source failed")
+            }
+            Error::PngloadError => write!(f, "vips error: PngloadError - pngload failed"),
+            Error::PngloadBufferError => {
+                write!(f, "vips error: PngloadBufferError - pngload_buffer failed")
+            }
+            Error::PngloadSourceError => {
+                write!(f, "vips error: PngloadSourceError - pngload_source failed")
+            }
+            Error::MatloadError => write!(f, "vips error: MatloadError - matload failed"),
+            Error::JpegloadError => write!(f, "vips error: JpegloadError - jpegload failed"),
+            Error::JpegloadBufferError => write!(
                 f,
-                "vips error: GreyError. Check error buffer for more details"
+                "vips error: JpegloadBufferError - jpegload_buffer failed"
             ),
-            Error::ZoneError => write!(
+            Error::WebploadError => write!(f, "vips error: WebploadError - webpload failed"),
+            Error::WebploadBufferError => write!(
                 f,
-                "vips error: ZoneError. Check error buffer for more details"
+                "vips error: WebploadBufferError - webpload_buffer failed"
             ),
-            Error::SinesError => write!(
+            Error::WebploadSourceError => write!(
                 f,
-                "vips error: SinesError. Check error buffer for more details"
+                "vips error: WebploadSourceError - webpload_source failed"
             ),
-            Error::MaskIdealError => write!(
+            Error::TiffloadError => write!(f, "vips error: TiffloadError - tiffload failed"),
+            Error::TiffloadBufferError => write!(
                 f,
-                "vips error: MaskIdealError. Check error buffer for more details"
+                "vips error: TiffloadBufferError - tiffload_buffer failed"
             ),
-            Error::MaskIdealRingError => write!(
+            Error::TiffloadSourceError => write!(
                 f,
-                "vips error: MaskIdealRingError. Check error buffer for more details"
+                "vips error: TiffloadSourceError - tiffload_source failed"
             ),
-            Error::MaskIdealBandError => write!(
+            Error::FitsloadError => write!(f, "vips error: FitsloadError - fitsload failed"),
+            Error::FitsloadSourceError => write!(
                 f,
-                "vips error: MaskIdealBandError. Check error buffer for more details"
+                "vips error: FitsloadSourceError - fitsload_source failed"
             ),
-            Error::MaskButterworthError => write!(
+            Error::OpenslideloadError => {
+                write!(f, "vips error: OpenslideloadError - openslideload failed")
+            }
+            Error::OpenslideloadSourceError => write!(
                 f,
-                "vips error: MaskButterworthError. Check error buffer for more details"
+                "vips error: OpenslideloadSourceError - openslideload_source failed"
             ),
-            Error::MaskButterworthRingError => write!(
+            Error::MagickloadError => write!(f, "vips error: MagickloadError - magickload failed"),
+            Error::MagickloadBufferError => write!(
                 f,
-                "vips error: MaskButterworthRingError. Check error buffer for more details"
+                "vips error: MagickloadBufferError - magickload_buffer failed"
             ),
-            Error::MaskButterworthBandError => write!(
+            Error::MagickloadSourceError => write!(
                 f,
-                "vips error: MaskButterworthBandError. Check error buffer for more details"
+                "vips error: MagickloadSourceError - magickload_source failed"
             ),
-            Error::MaskGaussianError => write!(
+            Error::HeifloadError => write!(f, "vips error: HeifloadError - heifload failed"),
+            Error::HeifloadBufferError => write!(
                 f,
-                "vips error: MaskGaussianError. Check error buffer for more details"
+                "vips error: HeifloadBufferError - heifload_buffer failed"
             ),
-            Error::MaskGaussianRingError => write!(
+            Error::HeifloadSourceError => write!(
                 f,
-                "vips error: MaskGaussianRingError. Check error buffer for more details"
+                "vips error: HeifloadSourceError - heifload_source failed"
             ),
-            Error::MaskGaussianBandError => write!(
+            Error::NiftiloadError => write!(f, "vips error: NiftiloadError - niftiload failed"),
+            Error::NiftiloadSourceError => write!(
                 f,
-                "vips error: MaskGaussianBandError. Check error buffer for more details"
+                "vips error: NiftiloadSourceError - niftiload_source failed"
             ),
-            Error::MaskFractalError => write!(
+            Error::CsvsaveError => write!(f, "vips error: CsvsaveError - csvsave failed"),
+            Error::CsvsaveTargetError => {
+                write!(f, "vips error: CsvsaveTargetError - csvsave_target failed")
+            }
+            Error::MatrixsaveError => write!(f, "vips error: MatrixsaveError - matrixsave failed"),
+            Error::MatrixsaveTargetError => write!(
                 f,
-                "vips error: MaskFractalError. Check error buffer for more details"
+                "vips error: MatrixsaveTargetError - matrixsave_target failed"
             ),
-            Error::BuildlutError => write!(
+            Error::MatrixprintError => {
+                write!(f, "vips error: MatrixprintError - matrixprint failed")
+            }
+            Error::RawsaveError => write!(f, "vips error: RawsaveError - rawsave failed"),
+            Error::RawsaveBufferError => {
+                write!(f, "vips error: RawsaveBufferError - rawsave_buffer failed")
+            }
+            Error::RawsaveTargetError => {
+                write!(f, "vips error: RawsaveTargetError - rawsave_target failed")
+            }
+            Error::VipssaveError => write!(f, "vips error: VipssaveError - vipssave failed"),
+            Error::VipssaveTargetError => write!(
                 f,
-                "vips error: BuildlutError. Check error buffer for more details"
+                "vips error: VipssaveTargetError - vipssave_target failed"
             ),
-            Error::InvertlutError => write!(
+            Error::PpmsaveError => write!(f, "vips error: PpmsaveError - ppmsave failed"),
+            Error::PpmsaveTargetError => {
+                write!(f, "vips error: PpmsaveTargetError - ppmsave_target failed")
+            }
+            Error::RadsaveError => write!(f, "vips error: RadsaveError - radsave failed"),
+            Error::RadsaveBufferError => {
+                write!(f, "vips error: RadsaveBufferError - radsave_buffer failed")
+            }
+            Error::RadsaveTargetError => {
+                write!(f, "vips error: RadsaveTargetError - radsave_target failed")
+            }
+            Error::DzsaveError => write!(f, "vips error: DzsaveError - dzsave failed"),
+            Error::DzsaveBufferError => {
+                write!(f, "vips error: DzsaveBufferError - dzsave_buffer failed")
+            }
+            Error::DzsaveTargetError => {
+                write!(f, "vips error: DzsaveTargetError - dzsave_target failed")
+            }
+            Error::GifsaveError => write!(f, "vips error: GifsaveError - gifsave failed"),
+            Error::GifsaveBufferError => {
+                write!(f, "vips error: GifsaveBufferError - gifsave_buffer failed")
+            }
+            Error::GifsaveTargetError => {
+                write!(f, "vips error: GifsaveTargetError - gifsave_target failed")
+            }
+            Error::PngsaveError => write!(f, "vips error: PngsaveError - pngsave failed"),
+            Error::PngsaveBufferError => {
+                write!(f, "vips error: PngsaveBufferError - pngsave_buffer failed")
+            }
+            Error::PngsaveTargetError => {
+                write!(f, "vips error: PngsaveTargetError - pngsave_target failed")
+            }
+            Error::JpegsaveError => write!(f, "vips error: JpegsaveError - jpegsave failed"),
+            Error::JpegsaveBufferError => write!(
                 f,
-                "vips error: InvertlutError. Check error buffer for more details"
+                "vips error: JpegsaveBufferError - jpegsave_buffer failed"
             ),
-            Error::TonelutError => write!(
+            Error::JpegsaveTargetError => write!(
                 f,
-                "vips error: TonelutError. Check error buffer for more details"
+                "vips error: JpegsaveTargetError - jpegsave_target failed"
             ),
-            Error::IdentityError => write!(
+            Error::JpegsaveMimeError => {
+                write!(f, "vips error: JpegsaveMimeError - jpegsave_mime failed")
+            }
+            Error::WebpsaveError => write!(f, "vips error: WebpsaveError - webpsave failed"),
+            Error::WebpsaveBufferError => write!(
                 f,
-                "vips error: IdentityError. Check error buffer for more details"
+                "vips error: WebpsaveBufferError - webpsave_buffer failed"
             ),
-            Error::FractsurfError => write!(
+            Error::WebpsaveTargetError => write!(
                 f,
-                "vips error: FractsurfError. Check error buffer for more details"
+                "vips error: WebpsaveTargetError - webpsave_target failed"
             ),
-            Error::WorleyError => write!(
+            Error::WebpsaveMimeError => {
+                write!(f, "vips error: WebpsaveMimeError - webpsave_mime failed")
+            }
+            Error::TiffsaveError => write!(f, "vips error: TiffsaveError - tiffsave failed"),
+            Error::TiffsaveBufferError => write!(
                 f,
-                "vips error: WorleyError. Check error buffer for more details"
+                "vips error: TiffsaveBufferError - tiffsave_buffer failed"
             ),
-            Error::PerlinError => write!(
+            Error::TiffsaveTargetError => write!(
                 f,
-                "vips error: PerlinError. Check error buffer for more details"
+                "vips error: TiffsaveTargetError - tiffsave_target failed"
             ),
-            Error::SwitchError => write!(
+            Error::Jp2ksaveError => write!(f, "vips error: Jp2ksaveError - jp2ksave failed"),
+            Error::Jp2ksaveBufferError => write!(
                 f,
-                "vips error: SwitchError. Check error buffer for more details"
+                "vips error: Jp2ksaveBufferError - jp2ksave_buffer failed"
             ),
-            Error::CsvloadError => write!(
+            Error::Jp2ksaveTargetError => write!(
                 f,
-                "vips error: CsvloadError. Check error buffer for more details"
+                "vips error: Jp2ksaveTargetError - jp2ksave_target failed"
             ),
-            Error::CsvloadSourceError => write!(
+            Error::FitssaveError => write!(f, "vips error: FitssaveError - fitssave failed"),
+            Error::MagicksaveError => write!(f, "vips error: MagicksaveError - magicksave failed"),
+            Error::MagicksaveBufferError => write!(
                 f,
-                "vips error: CsvloadSourceError. Check error buffer for more details"
+                "vips error: MagicksaveBufferError - magicksave_buffer failed"
             ),
-            Error::MatrixloadError => write!(
+            Error::HeifsaveError => write!(f, "vips error: HeifsaveError - heifsave failed"),
+            Error::HeifsaveBufferError => write!(
                 f,
-                "vips error: MatrixloadError. Check error buffer for more details"
+                "vips error: HeifsaveBufferError - heifsave_buffer failed"
             ),
-            Error::MatrixloadSourceError => write!(
+            Error::HeifsaveTargetError => write!(
                 f,
-                "vips error: MatrixloadSourceError. Check error buffer for more details"
+                "vips error: HeifsaveTargetError - heifsave_target failed"
             ),
-            Error::RawloadError => write!(
+            Error::JxlsaveError => write!(f, "vips error: JxlsaveError - jxlsave failed"),
+            Error::JxlsaveBufferError => {
+                write!(f, "vips error: JxlsaveBufferError - jxlsave_buffer failed")
+            }
+            Error::JxlsaveTargetError => {
+                write!(f, "vips error: JxlsaveTargetError - jxlsave_target failed")
+            }
+            Error::NiftisaveError => write!(f, "vips error: NiftisaveError - niftisave failed"),
+            Error::ThumbnailError => write!(f, "vips error: ThumbnailError - thumbnail failed"),
+            Error::ThumbnailBufferError => write!(
                 f,
-                "vips error: RawloadError. Check error buffer for more details"
+                "vips error: ThumbnailBufferError - thumbnail_buffer failed"
             ),
-            Error::VipsloadError => write!(
+            Error::ThumbnailImageError => write!(
                 f,
-                "vips error: VipsloadError. Check error buffer for more details"
+                "vips error: ThumbnailImageError - thumbnail_image failed"
             ),
-            Error::VipsloadSourceError => write!(
+            Error::ThumbnailSourceError => write!(
                 f,
-                "vips error: VipsloadSourceError. Check error buffer for more details"
-            ),
-            Error::AnalyzeloadError => write!(
+                "vips error: ThumbnailSourceError - thumbnail_source failed"
+            ),
+            Error::MapimError => write!(f, "vips error: MapimError - mapim failed"),
+            Error::ShrinkError => write!(f, "vips error: ShrinkError - shrink failed"),
+            Error::ShrinkhError => write!(f, "vips error: ShrinkhError - shrinkh failed"),
+            Error::ShrinkvError => write!(f, "vips error: ShrinkvError - shrinkv failed"),
+            Error::ReducehError => write!(f, "vips error: ReducehError - reduceh failed"),
+            Error::ReducevError => write!(f, "vips error: ReducevError - reducev failed"),
+            Error::ReduceError => write!(f, "vips error: ReduceError - reduce failed"),
+            Error::QuadraticError => write!(f, "vips error: QuadraticError - quadratic failed"),
+            Error::AffineError => write!(f, "vips error: AffineError - affine failed"),
+            Error::SimilarityError => write!(f, "vips error: SimilarityError - similarity failed"),
+            Error::RotateError => write!(f, "vips error: RotateError - rotate failed"),
+            Error::ResizeError => write!(f, "vips error: ResizeError - resize failed"),
+            Error::ColourspaceError => {
+                write!(f, "vips error: ColourspaceError - colourspace failed")
+            }
+            Error::Oklab2OklchError => {
+                write!(f, "vips error: Oklab2OklchError - Oklab2Oklch failed")
+            }
+            Error::Oklch2OklabError => {
+                write!(f, "vips error: Oklch2OklabError - Oklch2Oklab failed")
+            }
+            Error::Oklab2XyzError => write!(f, "vips error: Oklab2XyzError - Oklab2XYZ failed"),
+            Error::Xyz2OklabError => write!(f, "vips error: Xyz2OklabError - XYZ2Oklab failed"),
+            Error::Lab2XyzError => write!(f, "vips error: Lab2XyzError - Lab2XYZ failed"),
+            Error::Xyz2LabError => write!(f, "vips error: Xyz2LabError - XYZ2Lab failed"),
+            Error::Lab2LChError => write!(f, "vips error: Lab2LChError - Lab2LCh failed"),
+            Error::LCh2LabError => write!(f, "vips error: LCh2LabError - LCh2Lab failed"),
+            Error::LCh2CmcError => write!(f, "vips error: LCh2CmcError - LCh2CMC failed"),
+            Error::Cmc2lChError => write!(f, "vips error: Cmc2lChError - CMC2LCh failed"),
+            Error::Xyz2YxyError => write!(f, "vips error: Xyz2YxyError - XYZ2Yxy failed"),
+            Error::Yxy2XyzError => write!(f, "vips error: Yxy2XyzError - Yxy2XYZ failed"),
+            Error::ScRgb2xyzError => write!(f, "vips error: ScRgb2xyzError - scRGB2XYZ failed"),
+            Error::Xyz2scRgbError => write!(f, "vips error: Xyz2scRgbError - XYZ2scRGB failed"),
+            Error::LabQ2LabError => write!(f, "vips error: LabQ2LabError - LabQ2Lab failed"),
+            Error::Lab2LabQError => write!(f, "vips error: Lab2LabQError - Lab2LabQ failed"),
+            Error::LabQ2LabSError => write!(f, "vips error: LabQ2LabSError - LabQ2LabS failed"),
+            Error::LabS2LabQError => write!(f, "vips error: LabS2LabQError - LabS2LabQ failed"),
+            Error::LabS2LabError => write!(f, "vips error: LabS2LabError - LabS2Lab failed"),
+            Error::Lab2LabSError => write!(f, "vips error: Lab2LabSError - Lab2LabS failed"),
+            Error::Rad2floatError => write!(f, "vips error: Rad2floatError - rad2float failed"),
+            Error::Float2radError => write!(f, "vips error: Float2radError - float2rad failed"),
+            Error::LabQ2sRgbError => write!(f, "vips error: LabQ2sRgbError - LabQ2sRGB failed"),
+            Error::SRgb2scRgbError => write!(f, "vips error: SRgb2scRgbError - sRGB2scRGB failed"),
+            Error::ScRgb2bwError => write!(f, "vips error: ScRgb2bwError - scRGB2BW failed"),
+            Error::SRgb2hsvError => write!(f, "vips error: SRgb2hsvError - sRGB2HSV failed"),
+            Error::Hsv2sRgbError => write!(f, "vips error: Hsv2sRgbError - HSV2sRGB failed"),
+            Error::ScRgb2sRgbError => write!(f, "vips error: ScRgb2sRgbError - scRGB2sRGB failed"),
+            Error::IccImportError => write!(f, "vips error: IccImportError - icc_import failed"),
+            Error::IccExportError => write!(f, "vips error: IccExportError - icc_export failed"),
+            Error::IccTransformError => {
+                write!(f, "vips error: IccTransformError - icc_transform failed")
+            }
+            Error::Uhdr2scRgbError => write!(f, "vips error: Uhdr2scRgbError - uhdr2scRGB failed"),
+            Error::DE76Error => write!(f, "vips error: DE76Error - dE76 failed"),
+            Error::DE00Error => write!(f, "vips error: DE00Error - dE00 failed"),
+            Error::DEcmcError => write!(f, "vips error: DEcmcError - dECMC failed"),
+            Error::Cmyk2xyzError => write!(f, "vips error: Cmyk2xyzError - CMYK2XYZ failed"),
+            Error::Xyz2cmykError => write!(f, "vips error: Xyz2cmykError - XYZ2CMYK failed"),
+            Error::ProfileLoadError => {
+                write!(f, "vips error: ProfileLoadError - profile_load failed")
+            }
+            Error::MaplutError => write!(f, "vips error: MaplutError - maplut failed"),
+            Error::PercentError => write!(f, "vips error: PercentError - percent failed"),
+            Error::StdifError => write!(f, "vips error: StdifError - stdif failed"),
+            Error::HistCumError => write!(f, "vips error: HistCumError - hist_cum failed"),
+            Error::HistMatchError => write!(f, "vips error: HistMatchError - hist_match failed"),
+            Error::HistNormError => write!(f, "vips error: HistNormError - hist_norm failed"),
+            Error::HistEqualError => write!(f, "vips error: HistEqualError - hist_equal failed"),
+            Error::HistPlotError => write!(f, "vips error: HistPlotError - hist_plot failed"),
+            Error::HistLocalError => write!(f, "vips error: HistLocalError - hist_local failed"),
+            Error::HistIsmonotonicError => write!(
                 f,
-                "vips error: AnalyzeloadError. Check error buffer for more details"
-            ),
-            Error::PpmloadError => write!(
-                f,
-                "vips error: PpmloadError. Check error buffer for more details"
-            ),
-            Error::PpmloadBufferError => write!(
-                f,
-                "vips error: PpmloadBufferError. Check error buffer for more details"
-            ),
-            Error::PpmloadSourceError => write!(
-                f,
-                "vips error: PpmloadSourceError. Check error buffer for more details"
-            ),
-            Error::RadloadError => write!(
-                f,
-                "vips error: RadloadError. Check error buffer for more details"
-            ),
-            Error::RadloadBufferError => write!(
-                f,
-                "vips error: RadloadBufferError. Check error buffer for more details"
-            ),
-            Error::RadloadSourceError => write!(
-                f,
-                "vips error: RadloadSourceError. Check error buffer for more details"
-            ),
-            Error::SvgloadError => write!(
-                f,
-                "vips error: SvgloadError. Check error buffer for more details"
-            ),
-            Error::SvgloadBufferError => write!(
-                f,
-                "vips error: SvgloadBufferError. Check error buffer for more details"
-            ),
-            Error::GifloadError => write!(
-                f,
-                "vips error: GifloadError. Check error buffer for more details"
-            ),
-            Error::GifloadBufferError => write!(
-                f,
-                "vips error: GifloadBufferError. Check error buffer for more details"
-            ),
-            Error::GifloadSourceError => write!(
-                f,
-                "vips error: GifloadSourceError. Check error buffer for more details"
-            ),
-            Error::PngloadError => write!(
-                f,
-                "vips error: PngloadError. Check error buffer for more details"
-            ),
-            Error::PngloadBufferError => write!(
-                f,
-                "vips error: PngloadBufferError. Check error buffer for more details"
-            ),
-            Error::PngloadSourceError => write!(
-                f,
-                "vips error: PngloadSourceError. Check error buffer for more details"
-            ),
-            Error::JpegloadError => write!(
-                f,
-                "vips error: JpegloadError. Check error buffer for more details"
-            ),
-            Error::JpegloadBufferError => write!(
-                f,
-                "vips error: JpegloadBufferError. Check error buffer for more details"
-            ),
-            Error::WebploadError => write!(
-                f,
-                "vips error: WebploadError. Check error buffer for more details"
-            ),
-            Error::WebploadBufferError => write!(
-                f,
-                "vips error: WebploadBufferError. Check error buffer for more details"
-            ),
-            Error::WebploadSourceError => write!(
-                f,
-                "vips error: WebploadSourceError. Check error buffer for more details"
-            ),
-            Error::TiffloadError => write!(
-                f,
-                "vips error: TiffloadError. Check error buffer for more details"
-            ),
-            Error::TiffloadBufferError => write!(
-                f,
-                "vips error: TiffloadBufferError. Check error buffer for more details"
-            ),
-            Error::TiffloadSourceError => write!(
-                f,
-                "vips error: TiffloadSourceError. Check error buffer for more details"
-            ),
-            Error::HeifloadError => write!(
-                f,
-                "vips error: HeifloadError. Check error buffer for more details"
-            ),
-            Error::HeifloadBufferError => write!(
-                f,
-                "vips error: HeifloadBufferError. Check error buffer for more details"
-            ),
-            Error::HeifloadSourceError => write!(
-                f,
-                "vips error: HeifloadSourceError. Check error buffer for more details"
-            ),
-            Error::CsvsaveError => write!(
-                f,
-                "vips error: CsvsaveError. Check error buffer for more details"
-            ),
-            Error::CsvsaveTargetError => write!(
-                f,
-                "vips error: CsvsaveTargetError. Check error buffer for more details"
-            ),
-            Error::MatrixsaveError => write!(
-                f,
-                "vips error: MatrixsaveError. Check error buffer for more details"
-            ),
-            Error::MatrixsaveTargetError => write!(
-                f,
-                "vips error: MatrixsaveTargetError. Check error buffer for more details"
-            ),
-            Error::MatrixprintError => write!(
-                f,
-                "vips error: MatrixprintError. Check error buffer for more details"
-            ),
-            Error::RawsaveError => write!(
-                f,
-                "vips error: RawsaveError. Check error buffer for more details"
-            ),
-            Error::RawsaveBufferError => write!(
-                f,
-                "vips error: RawsaveBufferError. Check error buffer for more details"
-            ),
-            Error::RawsaveTargetError => write!(
-                f,
-                "vips error: RawsaveTargetError. Check error buffer for more details"
-            ),
-            Error::VipssaveError => write!(
-                f,
-                "vips error: VipssaveError. Check error buffer for more details"
-            ),
-            Error::VipssaveTargetError => write!(
-                f,
-                "vips error: VipssaveTargetError. Check error buffer for more details"
-            ),
-            Error::PpmsaveError => write!(
-                f,
-                "vips error: PpmsaveError. Check error buffer for more details"
-            ),
-            Error::PpmsaveTargetError => write!(
-                f,
-                "vips error: PpmsaveTargetError. Check error buffer for more details"
-            ),
-            Error::RadsaveError => write!(
-                f,
-                "vips error: RadsaveError. Check error buffer for more details"
-            ),
-            Error::RadsaveBufferError => write!(
-                f,
-                "vips error: RadsaveBufferError. Check error buffer for more details"
-            ),
-            Error::RadsaveTargetError => write!(
-                f,
-                "vips error: RadsaveTargetError. Check error buffer for more details"
-            ),
-            Error::GifsaveError => write!(
-                f,
-                "vips error: GifsaveError. Check error buffer for more details"
-            ),
-            Error::GifsaveBufferError => write!(
-                f,
-                "vips error: GifsaveBufferError. Check error buffer for more details"
-            ),
-            Error::GifsaveTargetError => write!(
-                f,
-                "vips error: GifsaveTargetError. Check error buffer for more details"
-            ),
-            Error::PngsaveError => write!(
-                f,
-                "vips error: PngsaveError. Check error buffer for more details"
-            ),
-            Error::PngsaveBufferError => write!(
-                f,
-                "vips error: PngsaveBufferError. Check error buffer for more details"
-            ),
-            Error::PngsaveTargetError => write!(
-                f,
-                "vips error: PngsaveTargetError. Check error buffer for more details"
-            ),
-            Error::JpegsaveError => write!(
-                f,
-                "vips error: JpegsaveError. Check error buffer for more details"
-            ),
-            Error::JpegsaveBufferError => write!(
-                f,
-                "vips error: JpegsaveBufferError. Check error buffer for more details"
-            ),
-            Error::JpegsaveTargetError => write!(
-                f,
-                "vips error: JpegsaveTargetError. Check error buffer for more details"
-            ),
-            Error::JpegsaveMimeError => write!(
-                f,
-                "vips error: JpegsaveMimeError. Check error buffer for more details"
-            ),
-            Error::WebpsaveError => write!(
-                f,
-                "vips error: WebpsaveError. Check error buffer for more details"
-            ),
-            Error::WebpsaveBufferError => write!(
-                f,
-                "vips error: WebpsaveBufferError. Check error buffer for more details"
-            ),
-            Error::WebpsaveTargetError => write!(
-                f,
-                "vips error: WebpsaveTargetError. Check error buffer for more details"
-            ),
-            Error::WebpsaveMimeError => write!(
-                f,
-                "vips error: WebpsaveMimeError. Check error buffer for more details"
-            ),
-            Error::TiffsaveError => write!(
-                f,
-                "vips error: TiffsaveError. Check error buffer for more details"
-            ),
-            Error::TiffsaveBufferError => write!(
-                f,
-                "vips error: TiffsaveBufferError. Check error buffer for more details"
-            ),
-            Error::TiffsaveTargetError => write!(
-                f,
-                "vips error: TiffsaveTargetError. Check error buffer for more details"
-            ),
-            Error::HeifsaveError => write!(
-                f,
-                "vips error: HeifsaveError. Check error buffer for more details"
-            ),
-            Error::HeifsaveBufferError => write!(
-                f,
-                "vips error: HeifsaveBufferError. Check error buffer for more details"
-            ),
-            Error::HeifsaveTargetError => write!(
-                f,
-                "vips error: HeifsaveTargetError. Check error buffer for more details"
-            ),
-            Error::ThumbnailError => write!(
-                f,
-                "vips error: ThumbnailError. Check error buffer for more details"
-            ),
-            Error::ThumbnailBufferError => write!(
-                f,
-                "vips error: ThumbnailBufferError. Check error buffer for more details"
-            ),
-            Error::ThumbnailImageError => write!(
-                f,
-                "vips error: ThumbnailImageError. Check error buffer for more details"
-            ),
-            Error::ThumbnailSourceError => write!(
-                f,
-                "vips error: ThumbnailSourceError. Check error buffer for more details"
-            ),
-            Error::MapimError => write!(
-                f,
-                "vips error: MapimError. Check error buffer for more details"
-            ),
-            Error::ShrinkError => write!(
-                f,
-                "vips error: ShrinkError. Check error buffer for more details"
-            ),
-            Error::ShrinkhError => write!(
-                f,
-                "vips error: ShrinkhError. Check error buffer for more details"
-            ),
-            Error::ShrinkvError => write!(
-                f,
-                "vips error: ShrinkvError. Check error buffer for more details"
-            ),
-            Error::ReducehError => write!(
-                f,
-                "vips error: ReducehError. Check error buffer for more details"
-            ),
-            Error::ReducevError => write!(
-                f,
-                "vips error: ReducevError. Check error buffer for more details"
-            ),
-            Error::ReduceError => write!(
-                f,
-                "vips error: ReduceError. Check error buffer for more details"
-            ),
-            Error::QuadraticError => write!(
-                f,
-                "vips error: QuadraticError. Check error buffer for more details"
-            ),
-            Error::AffineError => write!(
-                f,
-                "vips error: AffineError. Check error buffer for more details"
-            ),
-            Error::SimilarityError => write!(
-                f,
-                "vips error: SimilarityError. Check error buffer for more details"
-            ),
-            Error::RotateError => write!(
-                f,
-                "vips error: RotateError. Check error buffer for more details"
-            ),
-            Error::ResizeError => write!(
-                f,
-                "vips error: ResizeError. Check error buffer for more details"
-            ),
-            Error::ColourspaceError => write!(
-                f,
-                "vips error: ColourspaceError. Check error buffer for more details"
-            ),
-            Error::Oklab2OklchError => write!(
-                f,
-                "vips error: Oklab2OklchError. Check error buffer for more details"
-            ),
-            Error::Oklch2OklabError => write!(
-                f,
-                "vips error: Oklch2OklabError. Check error buffer for more details"
-            ),
-            Error::Oklab2XyzError => write!(
-                f,
-                "vips error: Oklab2XyzError. Check error buffer for more details"
-            ),
-            Error::Xyz2OklabError => write!(
-                f,
-                "vips error: Xyz2OklabError. Check error buffer for more details"
-            ),
-            Error::Lab2XyzError => write!(
-                f,
-                "vips error: Lab2XyzError. Check error buffer for more details"
-            ),
-            Error::Xyz2LabError => write!(
-                f,
-                "vips error: Xyz2LabError. Check error buffer for more details"
-            ),
-            Error::Lab2LChError => write!(
-                f,
-                "vips error: Lab2LChError. Check error buffer for more details"
-            ),
-            Error::LCh2LabError => write!(
-                f,
-                "vips error: LCh2LabError. Check error buffer for more details"
-            ),
-            Error::LCh2CmcError => write!(
-                f,
-                "vips error: LCh2CmcError. Check error buffer for more details"
-            ),
-            Error::Cmc2lChError => write!(
-                f,
-                "vips error: Cmc2lChError. Check error buffer for more details"
-            ),
-            Error::Xyz2YxyError => write!(
-                f,
-                "vips error: Xyz2YxyError. Check error buffer for more details"
-            ),
-            Error::Yxy2XyzError => write!(
-                f,
-                "vips error: Yxy2XyzError. Check error buffer for more details"
-            ),
-            Error::ScRgb2xyzError => write!(
-                f,
-                "vips error: ScRgb2xyzError. Check error buffer for more details"
-            ),
-            Error::Xyz2scRgbError => write!(
-                f,
-                "vips error: Xyz2scRgbError. Check error buffer for more details"
-            ),
-            Error::LabQ2LabError => write!(
-                f,
-                "vips error: LabQ2LabError. Check error buffer for more details"
-            ),
-            Error::Lab2LabQError => write!(
-                f,
-                "vips error: Lab2LabQError. Check error buffer for more details"
-            ),
-            Error::LabQ2LabSError => write!(
-                f,
-                "vips error: LabQ2LabSError. Check error buffer for more details"
-            ),
-            Error::LabS2LabQError => write!(
-                f,
-                "vips error: LabS2LabQError. Check error buffer for more details"
-            ),
-            Error::LabS2LabError => write!(
-                f,
-                "vips error: LabS2LabError. Check error buffer for more details"
-            ),
-            Error::Lab2LabSError => write!(
-                f,
-                "vips error: Lab2LabSError. Check error buffer for more details"
-            ),
-            Error::Rad2floatError => write!(
-                f,
-                "vips error: Rad2floatError. Check error buffer for more details"
-            ),
-            Error::Float2radError => write!(
-                f,
-                "vips error: Float2radError. Check error buffer for more details"
-            ),
-            Error::LabQ2sRgbError => write!(
-                f,
-                "vips error: LabQ2sRgbError. Check error buffer for more details"
-            ),
-            Error::SRgb2scRgbError => write!(
-                f,
-                "vips error: SRgb2scRgbError. Check error buffer for more details"
-            ),
-            Error::ScRgb2bwError => write!(
-                f,
-                "vips error: ScRgb2bwError. Check error buffer for more details"
-            ),
-            Error::SRgb2hsvError => write!(
-                f,
-                "vips error: SRgb2hsvError. Check error buffer for more details"
-            ),
-            Error::Hsv2sRgbError => write!(
-                f,
-                "vips error: Hsv2sRgbError. Check error buffer for more details"
-            ),
-            Error::ScRgb2sRgbError => write!(
-                f,
-                "vips error: ScRgb2sRgbError. Check error buffer for more details"
-            ),
-            Error::IccImportError => write!(
-                f,
-                "vips error: IccImportError. Check error buffer for more details"
-            ),
-            Error::IccExportError => write!(
-                f,
-                "vips error: IccExportError. Check error buffer for more details"
-            ),
-            Error::IccTransformError => write!(
-                f,
-                "vips error: IccTransformError. Check error buffer for more details"
-            ),
-            Error::Uhdr2scRgbError => write!(
-                f,
-                "vips error: Uhdr2scRgbError. Check error buffer for more details"
-            ),
-            Error::DE76Error => write!(
-                f,
-                "vips error: DE76Error. Check error buffer for more details"
-            ),
-            Error::DE00Error => write!(
-                f,
-                "vips error: DE00Error. Check error buffer for more details"
-            ),
-            Error::DEcmcError => write!(
-                f,
-                "vips error: DEcmcError. Check error buffer for more details"
-            ),
-            Error::Cmyk2xyzError => write!(
-                f,
-                "vips error: Cmyk2xyzError. Check error buffer for more details"
-            ),
-            Error::Xyz2cmykError => write!(
-                f,
-                "vips error: Xyz2cmykError. Check error buffer for more details"
-            ),
-            Error::ProfileLoadError => write!(
-                f,
-                "vips error: ProfileLoadError. Check error buffer for more details"
-            ),
-            Error::MaplutError => write!(
-                f,
-                "vips error: MaplutError. Check error buffer for more details"
-            ),
-            Error::PercentError => write!(
-                f,
-                "vips error: PercentError. Check error buffer for more details"
-            ),
-            Error::StdifError => write!(
-                f,
-                "vips error: StdifError. Check error buffer for more details"
-            ),
-            Error::HistCumError => write!(
-                f,
-                "vips error: HistCumError. Check error buffer for more details"
-            ),
-            Error::HistMatchError => write!(
-                f,
-                "vips error: HistMatchError. Check error buffer for more details"
-            ),
-            Error::HistNormError => write!(
-                f,
-                "vips error: HistNormError. Check error buffer for more details"
-            ),
-            Error::HistEqualError => write!(
-                f,
-                "vips error: HistEqualError. Check error buffer for more details"
-            ),
-            Error::HistPlotError => write!(
-                f,
-                "vips error: HistPlotError. Check error buffer for more details"
-            ),
-            Error::HistLocalError => write!(
-                f,
-                "vips error: HistLocalError. Check error buffer for more details"
-            ),
-            Error::HistIsmonotonicError => write!(
-                f,
-                "vips error: HistIsmonotonicError. Check error buffer for more details"
-            ),
-            Error::HistEntropyError => write!(
-                f,
-                "vips error: HistEntropyError. Check error buffer for more details"
-            ),
-            Error::ConvError => write!(
-                f,
-                "vips error: ConvError. Check error buffer for more details"
-            ),
-            Error::ConvaError => write!(
-                f,
-                "vips error: ConvaError. Check error buffer for more details"
-            ),
-            Error::ConvfError => write!(
-                f,
-                "vips error: ConvfError. Check error buffer for more details"
-            ),
-            Error::ConviError => write!(
-                f,
-                "vips error: ConviError. Check error buffer for more details"
-            ),
-            Error::CompassError => write!(
-                f,
-                "vips error: CompassError. Check error buffer for more details"
-            ),
-            Error::ConvsepError => write!(
-                f,
-                "vips error: ConvsepError. Check error buffer for more details"
-            ),
-            Error::ConvasepError => write!(
-                f,
-                "vips error: ConvasepError. Check error buffer for more details"
-            ),
-            Error::FastcorError => write!(
-                f,
-                "vips error: FastcorError. Check error buffer for more details"
-            ),
-            Error::SpcorError => write!(
-                f,
-                "vips error: SpcorError. Check error buffer for more details"
-            ),
-            Error::SharpenError => write!(
-                f,
-                "vips error: SharpenError. Check error buffer for more details"
-            ),
-            Error::GaussblurError => write!(
-                f,
-                "vips error: GaussblurError. Check error buffer for more details"
-            ),
-            Error::SobelError => write!(
-                f,
-                "vips error: SobelError. Check error buffer for more details"
-            ),
-            Error::ScharrError => write!(
-                f,
-                "vips error: ScharrError. Check error buffer for more details"
-            ),
-            Error::PrewittError => write!(
-                f,
-                "vips error: PrewittError. Check error buffer for more details"
-            ),
-            Error::CannyError => write!(
-                f,
-                "vips error: CannyError. Check error buffer for more details"
-            ),
-            Error::FwfftError => write!(
-                f,
-                "vips error: FwfftError. Check error buffer for more details"
-            ),
-            Error::InvfftError => write!(
-                f,
-                "vips error: InvfftError. Check error buffer for more details"
-            ),
-            Error::FreqmultError => write!(
-                f,
-                "vips error: FreqmultError. Check error buffer for more details"
-            ),
-            Error::SpectrumError => write!(
-                f,
-                "vips error: SpectrumError. Check error buffer for more details"
-            ),
-            Error::PhasecorError => write!(
-                f,
-                "vips error: PhasecorError. Check error buffer for more details"
-            ),
-            Error::MorphError => write!(
-                f,
-                "vips error: MorphError. Check error buffer for more details"
-            ),
-            Error::RankError => write!(
-                f,
-                "vips error: RankError. Check error buffer for more details"
-            ),
-            Error::CountlinesError => write!(
-                f,
-                "vips error: CountlinesError. Check error buffer for more details"
-            ),
-            Error::LabelregionsError => write!(
-                f,
-                "vips error: LabelregionsError. Check error buffer for more details"
-            ),
-            Error::FillNearestError => write!(
-                f,
-                "vips error: FillNearestError. Check error buffer for more details"
-            ),
-            Error::DrawRectError => write!(
-                f,
-                "vips error: DrawRectError. Check error buffer for more details"
-            ),
-            Error::DrawMaskError => write!(
-                f,
-                "vips error: DrawMaskError. Check error buffer for more details"
-            ),
-            Error::DrawLineError => write!(
-                f,
-                "vips error: DrawLineError. Check error buffer for more details"
-            ),
-            Error::DrawCircleError => write!(
-                f,
-                "vips error: DrawCircleError. Check error buffer for more details"
-            ),
-            Error::DrawFloodError => write!(
-                f,
-                "vips error: DrawFloodError. Check error buffer for more details"
-            ),
-            Error::DrawImageError => write!(
-                f,
-                "vips error: DrawImageError. Check error buffer for more details"
-            ),
-            Error::DrawSmudgeError => write!(
-                f,
-                "vips error: DrawSmudgeError. Check error buffer for more details"
-            ),
-            Error::MergeError => write!(
-                f,
-                "vips error: MergeError. Check error buffer for more details"
-            ),
-            Error::MosaicError => write!(
-                f,
-                "vips error: MosaicError. Check error buffer for more details"
-            ),
-            Error::Mosaic1Error => write!(
-                f,
-                "vips error: Mosaic1Error. Check error buffer for more details"
-            ),
-            Error::MatrixinvertError => write!(
-                f,
-                "vips error: MatrixinvertError. Check error buffer for more details"
-            ),
-            Error::MatrixmultiplyError => write!(
-                f,
-                "vips error: MatrixmultiplyError. Check error buffer for more details"
-            ),
-            Error::MatchesError => write!(
-                f,
-                "vips error: MatchesError. Check error buffer for more details"
-            ),
-            Error::GlobalbalanceError => write!(
-                f,
-                "vips error: GlobalbalanceError. Check error buffer for more details"
-            ),
-            Error::RemosaicError => write!(
-                f,
-                "vips error: RemosaicError. Check error buffer for more details"
+                "vips error: HistIsmonotonicError - hist_ismonotonic failed"
             ),
+            Error::HistEntropyError => {
+                write!(f, "vips error: HistEntropyError - hist_entropy failed")
+            }
+            Error::ConvError => write!(f, "vips error: ConvError - conv failed"),
+            Error::ConvaError => write!(f, "vips error: ConvaError - conva failed"),
+            Error::ConvfError => write!(f, "vips error: ConvfError - convf failed"),
+            Error::ConviError => write!(f, "vips error: ConviError - convi failed"),
+            Error::CompassError => write!(f, "vips error: CompassError - compass failed"),
+            Error::ConvsepError => write!(f, "vips error: ConvsepError - convsep failed"),
+            Error::ConvasepError => write!(f, "vips error: ConvasepError - convasep failed"),
+            Error::FastcorError => write!(f, "vips error: FastcorError - fastcor failed"),
+            Error::SpcorError => write!(f, "vips error: SpcorError - spcor failed"),
+            Error::SharpenError => write!(f, "vips error: SharpenError - sharpen failed"),
+            Error::GaussblurError => write!(f, "vips error: GaussblurError - gaussblur failed"),
+            Error::SobelError => write!(f, "vips error: SobelError - sobel failed"),
+            Error::ScharrError => write!(f, "vips error: ScharrError - scharr failed"),
+            Error::PrewittError => write!(f, "vips error: PrewittError - prewitt failed"),
+            Error::CannyError => write!(f, "vips error: CannyError - canny failed"),
+            Error::FwfftError => write!(f, "vips error: FwfftError - fwfft failed"),
+            Error::InvfftError => write!(f, "vips error: InvfftError - invfft failed"),
+            Error::FreqmultError => write!(f, "vips error: FreqmultError - freqmult failed"),
+            Error::SpectrumError => write!(f, "vips error: SpectrumError - spectrum failed"),
+            Error::PhasecorError => write!(f, "vips error: PhasecorError - phasecor failed"),
+            Error::MorphError => write!(f, "vips error: MorphError - morph failed"),
+            Error::RankError => write!(f, "vips error: RankError - rank failed"),
+            Error::CountlinesError => write!(f, "vips error: CountlinesError - countlines failed"),
+            Error::LabelregionsError => {
+                write!(f, "vips error: LabelregionsError - labelregions failed")
+            }
+            Error::FillNearestError => {
+                write!(f, "vips error: FillNearestError - fill_nearest failed")
+            }
+            Error::DrawRectError => write!(f, "vips error: DrawRectError - draw_rect failed"),
+            Error::DrawMaskError => write!(f, "vips error: DrawMaskError - draw_mask failed"),
+            Error::DrawLineError => write!(f, "vips error: DrawLineError - draw_line failed"),
+            Error::DrawCircleError => write!(f, "vips error: DrawCircleError - draw_circle failed"),
+            Error::DrawFloodError => write!(f, "vips error: DrawFloodError - draw_flood failed"),
+            Error::DrawImageError => write!(f, "vips error: DrawImageError - draw_image failed"),
+            Error::DrawSmudgeError => write!(f, "vips error: DrawSmudgeError - draw_smudge failed"),
+            Error::MergeError => write!(f, "vips error: MergeError - merge failed"),
+            Error::MosaicError => write!(f, "vips error: MosaicError - mosaic failed"),
+            Error::Mosaic1Error => write!(f, "vips error: Mosaic1Error - mosaic1 failed"),
+            Error::MatrixinvertError => {
+                write!(f, "vips error: MatrixinvertError - matrixinvert failed")
+            }
+            Error::MatrixmultiplyError => {
+                write!(f, "vips error: MatrixmultiplyError - matrixmultiply failed")
+            }
+            Error::MatchesError => write!(f, "vips error: MatchesError - match failed"),
+            Error::GlobalbalanceError => {
+                write!(f, "vips error: GlobalbalanceError - globalbalance failed")
+            }
+            Error::RemosaicError => write!(f, "vips error: RemosaicError - remosaic failed"),
         }
     }
 }
 
-impl std::error::Error for Error {}
+impl Error {
+    /// The error reported by the failing wrapper, without the libvips details
+    pub fn kind(&self) -> &Error {
+        match self {
+            Error::VipsError { kind, .. } => kind,
+            _ => self,
+        }
+    }
+
+    /// What libvips reported when the call failed
+    pub fn details(&self) -> Option<&ErrorDetails> {
+        match self {
+            Error::VipsError { details, .. } => Some(details),
+            _ => None,
+        }
+    }
+
+    /// Nickname of the libvips operation reporting this error
+    pub fn operation_nickname(&self) -> Option<&'static str> {
+        match self.kind() {
+            Error::LinearError => Some("linear"),
+            Error::CaseError => Some("case"),
+            Error::GetpointError => Some("getpoint"),
+            Error::SystemError => Some("system"),
+            Error::AddError => Some("add"),
+            Error::MinpairError => Some("minpair"),
+            Error::MaxpairError => Some("maxpair"),
+            Error::SubtractError => Some("subtract"),
+            Error::MultiplyError => Some("multiply"),
+            Error::DivideError => Some("divide"),
+            Error::RelationalError => Some("relational"),
+            Error::RemainderError => Some("remainder"),
+            Error::BooleanError => Some("boolean"),
+            Error::Math2Error => Some("math2"),
+            Error::Complex2Error => Some("complex2"),
+            Error::ComplexformError => Some("complexform"),
+            Error::ClampError => Some("clamp"),
+            Error::InvertError => Some("invert"),
+            Error::MathError => Some("math"),
+            Error::AbsError => Some("abs"),
+            Error::SignError => Some("sign"),
+            Error::RoundError => Some("round"),
+            Error::RelationalConstError => Some("relational_const"),
+            Error::RemainderConstError => Some("remainder_const"),
+            Error::BooleanConstError => Some("boolean_const"),
+            Error::Math2ConstError => Some("math2_const"),
+            Error::ComplexError => Some("complex"),
+            Error::ComplexgetError => Some("complexget"),
+            Error::SumError => Some("sum"),
+            Error::AvgError => Some("avg"),
+            Error::MinError => Some("min"),
+            Error::MaxError => Some("max"),
+            Error::DeviateError => Some("deviate"),
+            Error::StatsError => Some("stats"),
+            Error::HistFindError => Some("hist_find"),
+            Error::HistFindNdimError => Some("hist_find_ndim"),
+            Error::HistFindIndexedError => Some("hist_find_indexed"),
+            Error::HoughLineError => Some("hough_line"),
+            Error::HoughCircleError => Some("hough_circle"),
+            Error::ProjectError => Some("project"),
+            Error::ProfileError => Some("profile"),
+            Error::MeasureError => Some("measure"),
+            Error::FindTrimError => Some("find_trim"),
+            Error::CopyError => Some("copy"),
+            Error::TilecacheError => Some("tilecache"),
+            Error::LinecacheError => Some("linecache"),
+            Error::SequentialError => Some("sequential"),
+            Error::EmbedError => Some("embed"),
+            Error::GravityError => Some("gravity"),
+            Error::FlipError => Some("flip"),
+            Error::InsertError => Some("insert"),
+            Error::JoinError => Some("join"),
+            Error::ArrayjoinError => Some("arrayjoin"),
+            Error::ExtractAreaError => Some("extract_area"),
+            Error::SmartcropError => Some("smartcrop"),
+            Error::ExtractBandError => Some("extract_band"),
+            Error::BandjoinError => Some("bandjoin"),
+            Error::BandjoinConstError => Some("bandjoin_const"),
+            Error::BandrankError => Some("bandrank"),
+            Error::BandmeanError => Some("bandmean"),
+            Error::BandboolError => Some("bandbool"),
+            Error::ReplicateError => Some("replicate"),
+            Error::CastError => Some("cast"),
+            Error::RotError => Some("rot"),
+            Error::Rot45Error => Some("rot45"),
+            Error::AutorotError => Some("autorot"),
+            Error::IfthenelseError => Some("ifthenelse"),
+            Error::RecombError => Some("recomb"),
+            Error::BandfoldError => Some("bandfold"),
+            Error::BandunfoldError => Some("bandunfold"),
+            Error::FlattenError => Some("flatten"),
+            Error::PremultiplyError => Some("premultiply"),
+            Error::UnpremultiplyError => Some("unpremultiply"),
+            Error::GridError => Some("grid"),
+            Error::Transpose3dError => Some("transpose3d"),
+            Error::ScaleError => Some("scale"),
+            Error::WrapError => Some("wrap"),
+            Error::ZoomError => Some("zoom"),
+            Error::SubsampleError => Some("subsample"),
+            Error::MsbError => Some("msb"),
+            Error::ByteswapError => Some("byteswap"),
+            Error::FalsecolourError => Some("falsecolour"),
+            Error::GammaError => Some("gamma"),
+            Error::CompositeError => Some("composite"),
+            Error::Composite2Error => Some("composite2"),
+            Error::AddalphaError => Some("addalpha"),
+            Error::BlackError => Some("black"),
+            Error::GaussnoiseError => Some("gaussnoise"),
+            Error::XyzError => Some("xyz"),
+            Error::GaussmatError => Some("gaussmat"),
+            Error::LogmatError => Some("logmat"),
+            Error::TextError => Some("text"),
+            Error::SdfError => Some("sdf"),
+            Error::EyeError => Some("eye"),
+            Error::GreyError => Some("grey"),
+            Error::ZoneError => Some("zone"),
+            Error::SinesError => Some("sines"),
+            Error::MaskIdealError => Some("mask_ideal"),
+            Error::MaskIdealRingError => Some("mask_ideal_ring"),
+            Error::MaskIdealBandError => Some("mask_ideal_band"),
+            Error::MaskButterworthError => Some("mask_butterworth"),
+            Error::MaskButterworthRingError => Some("mask_butterworth_ring"),
+            Error::MaskButterworthBandError => Some("mask_butterworth_band"),
+            Error::MaskGaussianError => Some("mask_gaussian"),
+            Error::MaskGaussianRingError => Some("mask_gaussian_ring"),
+            Error::MaskGaussianBandError => Some("mask_gaussian_band"),
+            Error::MaskFractalError => Some("mask_fractal"),
+            Error::BuildlutError => Some("buildlut"),
+            Error::InvertlutError => Some("invertlut"),
+            Error::TonelutError => Some("tonelut"),
+            Error::IdentityError => Some("identity"),
+            Error::FractsurfError => Some("fractsurf"),
+            Error::WorleyError => Some("worley"),
+            Error::PerlinError => Some("perlin"),
+            Error::SwitchError => Some("switch"),
+            Error::CsvloadError => Some("csvload"),
+            Error::CsvloadSourceError => Some("csvload_source"),
+            Error::MatrixloadError => Some("matrixload"),
+            Error::MatrixloadSourceError => Some("matrixload_source"),
+            Error::RawloadError => Some("rawload"),
+            Error::VipsloadError => Some("vipsload"),
+            Error::VipsloadSourceError => Some("vipsload_source"),
+            Error::AnalyzeloadError => Some("analyzeload"),
+            Error::PpmloadError => Some("ppmload"),
+            Error::PpmloadBufferError => Some("ppmload_buffer"),
+            Error::PpmloadSourceError => Some("ppmload_source"),
+            Error::RadloadError => Some("radload"),
+            Error::RadloadBufferError => Some("radload_buffer"),
+            Error::RadloadSourceError => Some("radload_source"),
+            Error::SvgloadError => Some("svgload"),
+            Error::SvgloadBufferError => Some("svgload_buffer"),
+            Error::Jp2kloadError => Some("jp2kload"),
+            Error::Jp2kloadBufferError => Some("jp2kload_buffer"),
+            Error::Jp2kloadSourceError => Some("jp2kload_source"),
+            Error::JxlloadError => Some("jxlload"),
+            Error::JxlloadBufferError => Some("jxlload_buffer"),
+            Error::JxlloadSourceError => Some("jxlload_source"),
+            Error::PdfloadError => Some("pdfload"),
+            Error::PdfloadBufferError => Some("pdfload_buffer"),
+            Error::PdfloadSourceError => Some("pdfload_source"),
+            Error::GifloadError => Some("gifload"),
+            Error::GifloadBufferError => Some("gifload_buffer"),
+            Error::GifloadSourceError => Some("gifload_source"),
+            Error::PngloadError => Some("pngload"),
+            Error::PngloadBufferError => Some("pngload_buffer"),
+            Error::PngloadSourceError => Some("pngload_source"),
+            Error::MatloadError => Some("matload"),
+            Error::JpegloadError => Some("jpegload"),
+            Error::JpegloadBufferError => Some("jpegload_buffer"),
+            Error::WebploadError => Some("webpload"),
+            Error::WebploadBufferError => Some("webpload_buffer"),
+            Error::WebploadSourceError => Some("webpload_source"),
+            Error::TiffloadError => Some("tiffload"),
+            Error::TiffloadBufferError => Some("tiffload_buffer"),
+            Error::TiffloadSourceError => Some("tiffload_source"),
+            Error::FitsloadError => Some("fitsload"),
+            Error::FitsloadSourceError => Some("fitsload_source"),
+            Error::OpenslideloadError => Some("openslideload"),
+            Error::OpenslideloadSourceError => Some("openslideload_source"),
+            Error::MagickloadError => Some("magickload"),
+            Error::MagickloadBufferError => Some("magickload_buffer"),
+            Error::MagickloadSourceError => Some("magickload_source"),
+            Error::HeifloadError => Some("heifload"),
+            Error::HeifloadBufferError => Some("heifload_buffer"),
+            Error::HeifloadSourceError => Some("heifload_source"),
+            Error::NiftiloadError => Some("niftiload"),
+            Error::NiftiloadSourceError => Some("niftiload_source"),
+            Error::CsvsaveError => Some("csvsave"),
+            Error::CsvsaveTargetError => Some("csvsave_target"),
+            Error::MatrixsaveError => Some("matrixsave"),
+            Error::MatrixsaveTargetError => Some("matrixsave_target"),
+            Error::MatrixprintError => Some("matrixprint"),
+            Error::RawsaveError => Some("rawsave"),
+            Error::RawsaveBufferError => Some("rawsave_buffer"),
+            Error::RawsaveTargetError => Some("rawsave_target"),
+            Error::VipssaveError => Some("vipssave"),
+            Error::VipssaveTargetError => Some("vipssave_target"),
+            Error::PpmsaveError => Some("ppmsave"),
+            Error::PpmsaveTargetError => Some("ppmsave_target"),
+            Error::RadsaveError => Some("radsave"),
+            Error::RadsaveBufferError => Some("radsave_buffer"),
+            Error::RadsaveTargetError => Some("radsave_target"),
+            Error::DzsaveError => Some("dzsave"),
+            Error::DzsaveBufferError => Some("dzsave_buffer"),
+            Error::DzsaveTargetError => Some("dzsave_target"),
+            Error::GifsaveError => Some("gifsave"),
+            Error::GifsaveBufferError => Some("gifsave_buffer"),
+            Error::GifsaveTargetError => Some("gifsave_target"),
+            Error::PngsaveError => Some("pngsave"),
+            Error::PngsaveBufferError => Some("pngsave_buffer"),
+            Error::PngsaveTargetError => Some("pngsave_target"),
+            Error::JpegsaveError => Some("jpegsave"),
+            Error::JpegsaveBufferError => Some("jpegsave_buffer"),
+            Error::JpegsaveTargetError => Some("jpegsave_target"),
+            Error::JpegsaveMimeError => Some("jpegsave_mime"),
+            Error::WebpsaveError => Some("webpsave"),
+            Error::WebpsaveBufferError => Some("webpsave_buffer"),
+            Error::WebpsaveTargetError => Some("webpsave_target"),
+            Error::WebpsaveMimeError => Some("webpsave_mime"),
+            Error::TiffsaveError => Some("tiffsave"),
+            Error::TiffsaveBufferError => Some("tiffsave_buffer"),
+            Error::TiffsaveTargetError => Some("tiffsave_target"),
+            Error::Jp2ksaveError => Some("jp2ksave"),
+            Error::Jp2ksaveBufferError => Some("jp2ksave_buffer"),
+            Error::Jp2ksaveTargetError => Some("jp2ksave_target"),
+            Error::FitssaveError => Some("fitssave"),
+            Error::MagicksaveError => Some("magicksave"),
+            Error::MagicksaveBufferError => Some("magicksave_buffer"),
+            Error::HeifsaveError => Some("heifsave"),
+            Error::HeifsaveBufferError => Some("heifsave_buffer"),
+            Error::HeifsaveTargetError => Some("heifsave_target"),
+            Error::JxlsaveError => Some("jxlsave"),
+            Error::JxlsaveBufferError => Some("jxlsave_buffer"),
+            Error::JxlsaveTargetError => Some("jxlsave_target"),
+            Error::NiftisaveError => Some("niftisave"),
+            Error::ThumbnailError => Some("thumbnail"),
+            Error::ThumbnailBufferError => Some("thumbnail_buffer"),
+            Error::ThumbnailImageError => Some("thumbnail_image"),
+            Error::ThumbnailSourceError => Some("thumbnail_source"),
+            Error::MapimError => Some("mapim"),
+            Error::ShrinkError => Some("shrink"),
+            Error::ShrinkhError => Some("shrinkh"),
+            Error::ShrinkvError => Some("shrinkv"),
+            Error::ReducehError => Some("reduceh"),
+            Error::ReducevError => Some("reducev"),
+            Error::ReduceError => Some("reduce"),
+            Error::QuadraticError => Some("quadratic"),
+            Error::AffineError => Some("affine"),
+            Error::SimilarityError => Some("similarity"),
+            Error::RotateError => Some("rotate"),
+            Error::ResizeError => Some("resize"),
+            Error::ColourspaceError => Some("colourspace"),
+            Error::Oklab2OklchError => Some("Oklab2Oklch"),
+            Error::Oklch2OklabError => Some("Oklch2Oklab"),
+            Error::Oklab2XyzError => Some("Oklab2XYZ"),
+            Error::Xyz2OklabError => Some("XYZ2Oklab"),
+            Error::Lab2XyzError => Some("Lab2XYZ"),
+            Error::Xyz2LabError => Some("XYZ2Lab"),
+            Error::Lab2LChError => Some("Lab2LCh"),
+            Error::LCh2LabError => Some("LCh2Lab"),
+            Error::LCh2CmcError => Some("LCh2CMC"),
+            Error::Cmc2lChError => Some("CMC2LCh"),
+            Error::Xyz2YxyError => Some("XYZ2Yxy"),
+            Error::Yxy2XyzError => Some("Yxy2XYZ"),
+            Error::ScRgb2xyzError => Some("scRGB2XYZ"),
+            Error::Xyz2scRgbError => Some("XYZ2scRGB"),
+            Error::LabQ2LabError => Some("LabQ2Lab"),
+            Error::Lab2LabQError => Some("Lab2LabQ"),
+            Error::LabQ2LabSError => Some("LabQ2LabS"),
+            Error::LabS2LabQError => Some("LabS2LabQ"),
+            Error::LabS2LabError => Some("LabS2Lab"),
+            Error::Lab2LabSError => Some("Lab2LabS"),
+            Error::Rad2floatError => Some("rad2float"),
+            Error::Float2radError => Some("float2rad"),
+            Error::LabQ2sRgbError => Some("LabQ2sRGB"),
+            Error::SRgb2scRgbError => Some("sRGB2scRGB"),
+            Error::ScRgb2bwError => Some("scRGB2BW"),
+            Error::SRgb2hsvError => Some("sRGB2HSV"),
+            Error::Hsv2sRgbError => Some("HSV2sRGB"),
+            Error::ScRgb2sRgbError => Some("scRGB2sRGB"),
+            Error::IccImportError => Some("icc_import"),
+            Error::IccExportError => Some("icc_export"),
+            Error::IccTransformError => Some("icc_transform"),
+            Error::Uhdr2scRgbError => Some("uhdr2scRGB"),
+            Error::DE76Error => Some("dE76"),
+            Error::DE00Error => Some("dE00"),
+            Error::DEcmcError => Some("dECMC"),
+            Error::Cmyk2xyzError => Some("CMYK2XYZ"),
+            Error::Xyz2cmykError => Some("XYZ2CMYK"),
+            Error::ProfileLoadError => Some("profile_load"),
+            Error::MaplutError => Some("maplut"),
+            Error::PercentError => Some("percent"),
+            Error::StdifError => Some("stdif"),
+            Error::HistCumError => Some("hist_cum"),
+            Error::HistMatchError => Some("hist_match"),
+            Error::HistNormError => Some("hist_norm"),
+            Error::HistEqualError => Some("hist_equal"),
+            Error::HistPlotError => Some("hist_plot"),
+            Error::HistLocalError => Some("hist_local"),
+            Error::HistIsmonotonicError => Some("hist_ismonotonic"),
+            Error::HistEntropyError => Some("hist_entropy"),
+            Error::ConvError => Some("conv"),
+            Error::ConvaError => Some("conva"),
+            Error::ConvfError => Some("convf"),
+            Error::ConviError => Some("convi"),
+            Error::CompassError => Some("compass"),
+            Error::ConvsepError => Some("convsep"),
+            Error::ConvasepError => Some("convasep"),
+            Error::FastcorError => Some("fastcor"),
+            Error::SpcorError => Some("spcor"),
+            Error::SharpenError => Some("sharpen"),
+            Error::GaussblurError => Some("gaussblur"),
+            Error::SobelError => Some("sobel"),
+            Error::ScharrError => Some("scharr"),
+            Error::PrewittError => Some("prewitt"),
+            Error::CannyError => Some("canny"),
+            Error::FwfftError => Some("fwfft"),
+            Error::InvfftError => Some("invfft"),
+            Error::FreqmultError => Some("freqmult"),
+            Error::SpectrumError => Some("spectrum"),
+            Error::PhasecorError => Some("phasecor"),
+            Error::MorphError => Some("morph"),
+            Error::RankError => Some("rank"),
+            Error::CountlinesError => Some("countlines"),
+            Error::LabelregionsError => Some("labelregions"),
+            Error::FillNearestError => Some("fill_nearest"),
+            Error::DrawRectError => Some("draw_rect"),
+            Error::DrawMaskError => Some("draw_mask"),
+            Error::DrawLineError => Some("draw_line"),
+            Error::DrawCircleError => Some("draw_circle"),
+            Error::DrawFloodError => Some("draw_flood"),
+            Error::DrawImageError => Some("draw_image"),
+            Error::DrawSmudgeError => Some("draw_smudge"),
+            Error::MergeError => Some("merge"),
+            Error::MosaicError => Some("mosaic"),
+            Error::Mosaic1Error => Some("mosaic1"),
+            Error::MatrixinvertError => Some("matrixinvert"),
+            Error::MatrixmultiplyError => Some("matrixmultiply"),
+            Error::MatchesError => Some("match"),
+            Error::GlobalbalanceError => Some("globalbalance"),
+            Error::RemosaicError => Some("remosaic"),
+            _ => None,
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::VipsError { kind, .. } => Some(kind.as_ref()),
+            _ => None,
+        }
+    }
+}
@@ -3,48 +3,76 @@ use crate::bindings;
 use crate::error::Error;
 use crate::ops::*;
 use crate::utils;
+use crate::utils::{G_TYPE_BOOLEAN, G_TYPE_DOUBLE, G_TYPE_ENUM, G_TYPE_FLAGS, G_TYPE_INT, G_TYPE_STRING};
 use crate::Result;
 
 use num_traits::{FromPrimitive, ToPrimitive};
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::convert::TryInto;
 use std::ffi::*;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::panic::{self, AssertUnwindSafe};
 use std::ptr::null_mut;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 
 const NULL: *const c_void = null_mut();
 
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct VipsImage {
     pub(crate) ctx: *mut bindings::VipsImage,
 }
 
+/// A thread safe handle to a `VipsImage`. It can be cloned and shared between threads, and it
+/// derefs to `&VipsImage` so every operation taking an input image can be called on it.
+/// The `draw_*` operations modify their image in place and need a `&mut VipsImage`, which a
+/// `SharedImage` never hands out. A clone of the `&VipsImage` refers to the same libvips image, and
+/// drawing on it or setting its fields makes it a private copy first, so the shared one never changes.
 #[derive(Debug, Clone)]
+pub struct SharedImage {
+    image: Arc<VipsImage>,
+}
+
+/// The value of an image field, as returned by `VipsImage::fields`
+#[derive(Debug, Clone)]
+pub enum MetadataValue {
+    Int(i32),
+    Double(f64),
+    String(String),
+    Blob(Vec<u8>),
+    ArrayInt(Vec<i32>),
+    ArrayDouble(Vec<f64>),
+    Image(VipsImage),
+}
+
+#[derive(Debug)]
 pub struct VipsInterpolate {
     pub(crate) ctx: *mut bindings::VipsInterpolate,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct VipsBlob {
     pub(crate) ctx: *mut bindings::VipsBlob,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct VipsConnection {
     pub(crate) ctx: *mut bindings::VipsConnection,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct VipsSource {
     pub(crate) ctx: *mut bindings::VipsSource,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct VipsTarget {
     pub(crate) ctx: *mut bindings::VipsTarget,
 }
 
 /// This is the main type of vips. It represents an image and most operations will take one as input and output a new one.
-/// In the moment this type is not thread safe. Be careful working within thread environments.
+/// A `VipsImage` can be moved to another thread, but not shared between threads. Wrap it in a `SharedImage` for that.
 impl VipsImage {
     pub fn new() -> VipsImage {
         VipsImage {
@@ -60,6 +88,7 @@ impl VipsImage {
     }
 
     pub fn new_from_file(filename: &str) -> Result<VipsImage> {
+        utils::check_load_file_bytes(filename)?;
         unsafe {
             let f = utils::new_c_string(filename)?;
             let res = bindings::vips_image_new_from_file(f.as_ptr(), NULL);
@@ -67,6 +96,9 @@ impl VipsImage {
                 res,
                 Error::InitializationError("VipsImage:new_from_file - Could not initialise VipsImage"),
             )
+            .map_err(|e| utils::with_filename(e, filename))
+            .map_err(|e| utils::with_blocked_loader(e, "", || find_load_file(filename)))
+            .and_then(utils::checked_load)
         }
     }
 
@@ -78,6 +110,7 @@ impl VipsImage {
                 res,
                 Error::InitializationError("VipsImage:new_from_file_rw - Could not initialise VipsImage"),
             )
+            .map_err(|e| utils::with_filename(e, filename))
         }
     }
 
@@ -96,10 +129,12 @@ impl VipsImage {
                 res,
                 Error::InitializationError("VipsImage:new_from_file_raw - Could not initialise VipsImage"),
             )
+            .map_err(|e| utils::with_filename(e, filename))
         }
     }
 
     pub fn new_from_file_access(filename: &str, access: Access, memory: bool) -> Result<VipsImage> {
+        utils::check_load_file_bytes(filename)?;
         unsafe {
             let access_str = utils::new_c_string("access")?;
             let memory_str = utils::new_c_string("memory")?;
@@ -116,10 +151,14 @@ impl VipsImage {
                 res,
                 Error::InitializationError("VipsImage:new_from_file_access - Could not initialise VipsImage"),
             )
+            .map_err(|e| utils::with_filename(e, filename))
+            .map_err(|e| utils::with_blocked_loader(e, "", || find_load_file(filename)))
+            .and_then(utils::checked_load)
         }
     }
 
     pub fn new_from_buffer(buffer: &[u8], option_str: &str) -> Result<VipsImage> {
+        utils::check_load_bytes(buffer.len())?;
         unsafe {
             let options = utils::new_c_string(option_str)?;
             let res = bindings::vips_image_new_from_buffer(
@@ -132,11 +171,32 @@ impl VipsImage {
                 res,
                 Error::InitializationError("VipsImage:new_from_buffer - Could not initialise VipsImage"),
             )
+            .map_err(|e| {
+                utils::with_blocked_loader(e, "_buffer", || {
+                    bindings::vips_foreign_find_load_buffer(buffer.as_ptr() as *const c_void, buffer.len() as u64)
+                })
+            })
+            .and_then(utils::checked_load)
+        }
+    }
+
+    /// loads the source with the loader its content is detected as. like `new_from_buffer`, the
+    /// load options are given as a string, e.g. `"access=sequential,n=-1"`
+    pub fn new_from_source(source: &VipsSource, option_str: &str) -> Result<VipsImage> {
+        utils::check_load_source_bytes(source)?;
+        unsafe {
+            let options = utils::new_c_string(option_str)?;
+            let res = bindings::vips_image_new_from_source(source.ctx, options.as_ptr(), NULL);
+            vips_image_result(
+                res,
+                Error::InitializationError("VipsImage:new_from_source - Could not initialise VipsImage"),
+            )
+            .map_err(|e| utils::with_blocked_loader(e, "_source", || bindings::vips_foreign_find_load_source(source.ctx)))
+            .map_err(|e| utils::with_source_limit(e, source))
+            .and_then(utils::checked_load)
         }
     }
 
-    /// This is the main type of vips. It represents an image and most operations will take one as input and output a new one.
-    /// In the moment this type is not thread safe. Be careful working within thread environments.
     pub fn new_from_memory(
         buffer: &[u8],
         width: i32,
@@ -262,10 +322,12 @@ impl VipsImage {
         unsafe {
             let result = bindings::vips_image_copy_memory(image.ctx);
             vips_image_result(result, Error::OperationError("VipsImage:image_copy_memory - Could not copy memory"))
+                .map_err(|e| utils::with_cancelled(e, &image))
         }
     }
 
     pub fn image_wio_input(&mut self) -> Result<()> {
+        self.make_private()?;
         unsafe {
             let result = bindings::vips_image_wio_input(self.ctx);
             utils::result(
@@ -378,10 +440,12 @@ impl VipsImage {
         }
     }
 
-    pub fn image_invalidate_all(&self) {
+    pub fn image_invalidate_all(&mut self) -> Result<()> {
+        self.make_private()?;
         unsafe {
             bindings::vips_image_invalidate_all(self.ctx);
         }
+        Ok(())
     }
 
     pub fn image_minimise_all(&self) {
@@ -410,16 +474,20 @@ impl VipsImage {
         unsafe { bindings::vips_image_hasalpha(self.ctx) == 1 }
     }
 
-    pub fn image_set_kill(&self, flag: bool) {
+    pub fn image_set_kill(&mut self, flag: bool) -> Result<()> {
+        self.make_private()?;
         unsafe {
             bindings::vips_image_set_kill(self.ctx, if flag { 1 } else { 0 });
         }
+        Ok(())
     }
 
-    pub fn image_set_progress(&self, flag: bool) {
+    pub fn image_set_progress(&mut self, flag: bool) -> Result<()> {
+        self.make_private()?;
         unsafe {
             bindings::vips_image_set_progress(self.ctx, if flag { 1 } else { 0 });
         }
+        Ok(())
     }
 
     pub fn image_write(&self) -> Result<VipsImage> {
@@ -448,7 +516,8 @@ impl VipsImage {
         }
     }
 
-    pub fn image_inplace(&self) -> Result<()> {
+    pub fn image_inplace(&mut self) -> Result<()> {
+        self.make_private()?;
         unsafe {
             let res = bindings::vips_image_inplace(self.ctx);
             utils::result(res, || (), Error::IOError("VipsImage:image_inplace - Cannot be modified inplace"))
@@ -460,6 +529,7 @@ impl VipsImage {
             let file_c_str = utils::new_c_string(filename)?;
             let res = bindings::vips_image_write_to_file(self.ctx, file_c_str.as_ptr(), NULL);
             utils::result(res, || (), Error::IOError("VipsImage:image_write_to_file - Cannot write to file"))
+                .map_err(|e| utils::with_filename(utils::with_cancelled(e, self), filename))
         }
     }
 
@@ -487,16 +557,34 @@ impl VipsImage {
                 || utils::new_byte_array(buffer_out, buffer_buf_size),
                 Error::IOError("VipsImage:image_write_to_buffer - Cannot write content to buffer"),
             )
+            .map_err(|e| utils::with_cancelled(e, self))
+        }
+    }
+
+    /// saves to the target with the saver of the suffix, which can carry the save options like the
+    /// suffix of `image_write_to_buffer`, e.g. `".webp[Q=80]"`
+    pub fn image_write_to_target(&self, suffix: &str, target: &VipsTarget) -> Result<()> {
+        unsafe {
+            let suffix_c_str = utils::new_c_string(suffix)?;
+            let res = bindings::vips_image_write_to_target(self.ctx, suffix_c_str.as_ptr(), target.ctx, NULL);
+            utils::result(res, || (), Error::IOError("VipsImage:image_write_to_target - Cannot write content to target"))
+                .map_err(|e| utils::with_cancelled(e, self))
         }
     }
 
-    pub fn image_write_to_memory(&self) -> Vec<u8> {
+    pub fn image_write_to_memory(&self) -> Result<Vec<u8>> {
         unsafe {
             let mut buffer_buf_size: u64 = 0;
             let buffer_out = bindings::vips_image_write_to_memory(self.ctx, &mut buffer_buf_size);
+            if buffer_out.is_null() {
+                return Err(utils::with_cancelled(
+                    utils::vips_error(Error::IOError("VipsImage:image_write_to_memory - Cannot write image to memory")),
+                    self,
+                ));
+            }
             let buf = std::slice::from_raw_parts(buffer_out as *mut u8, buffer_buf_size as usize).to_vec();
             bindings::g_free(buffer_out);
-            buf
+            Ok(buf)
         }
     }
 
@@ -551,7 +639,7 @@ impl VipsImage {
             let result = bindings::vips_image_get_as_string(self.ctx, name_c_str.as_ptr(), &mut out);
 
             if result == -1 || out.is_null() {
-                return Err(Error::OperationError("VipsImage:get_as_string - Error on vips get_as_string or null pointer returned"));
+                return Err(utils::vips_error(Error::OperationError("VipsImage:get_as_string - Error on vips get_as_string or null pointer returned")));
             }
 
             let res_string = CStr::from_ptr(out)
@@ -563,6 +651,370 @@ impl VipsImage {
             Ok(res_string)
         }
     }
+
+    pub fn get_int(&self, name: &str) -> Result<i32> {
+        unsafe {
+            let name_c_str = utils::new_c_string(name)?;
+            self.check_field(name, &name_c_str, "int", is_int)?;
+            let mut out: i32 = 0;
+            let res = bindings::vips_image_get_int(self.ctx, name_c_str.as_ptr(), &mut out);
+            utils::result(res, || out, Error::OperationError("VipsImage:get_int - Could not read field"))
+        }
+    }
+
+    pub fn set_int(&mut self, name: &str, value: i32) -> Result<()> {
+        self.make_private()?;
+        unsafe {
+            let name_c_str = utils::new_c_string(name)?;
+            bindings::vips_image_set_int(self.ctx, name_c_str.as_ptr(), value);
+            Ok(())
+        }
+    }
+
+    pub fn get_double(&self, name: &str) -> Result<f64> {
+        unsafe {
+            let name_c_str = utils::new_c_string(name)?;
+            self.check_field(name, &name_c_str, "double", |gtype| {
+                bindings::g_value_type_transformable(gtype, G_TYPE_DOUBLE) != 0
+            })?;
+            let mut out: f64 = 0.0;
+            let res = bindings::vips_image_get_double(self.ctx, name_c_str.as_ptr(), &mut out);
+            utils::result(res, || out, Error::OperationError("VipsImage:get_double - Could not read field"))
+        }
+    }
+
+    pub fn set_double(&mut self, name: &str, value: f64) -> Result<()> {
+        self.make_private()?;
+        unsafe {
+            let name_c_str = utils::new_c_string(name)?;
+            bindings::vips_image_set_double(self.ctx, name_c_str.as_ptr(), value);
+            Ok(())
+        }
+    }
+
+    pub fn get_string(&self, name: &str) -> Result<String> {
+        unsafe {
+            let name_c_str = utils::new_c_string(name)?;
+            self.check_field(name, &name_c_str, "string", |gtype| {
+                gtype == G_TYPE_STRING || gtype == bindings::vips_ref_string_get_type()
+            })?;
+            let mut out: *const c_char = std::ptr::null();
+            let res = bindings::vips_image_get_string(self.ctx, name_c_str.as_ptr(), &mut out);
+            // the string is owned by the image, so it is copied and not freed
+            utils::result(
+                res,
+                || CStr::from_ptr(out).to_string_lossy().into_owned(),
+                Error::OperationError("VipsImage:get_string - Could not read field"),
+            )
+        }
+    }
+
+    pub fn set_string(&mut self, name: &str, value: &str) -> Result<()> {
+        self.make_private()?;
+        unsafe {
+            let name_c_str = utils::new_c_string(name)?;
+            let value_c_str = utils::new_c_string(value)?;
+            bindings::vips_image_set_string(self.ctx, name_c_str.as_ptr(), value_c_str.as_ptr());
+            Ok(())
+        }
+    }
+
+    pub fn get_blob(&self, name: &str) -> Result<Vec<u8>> {
+        unsafe {
+            let name_c_str = utils::new_c_string(name)?;
+            self.check_field(name, &name_c_str, "blob", |gtype| {
+                gtype == bindings::vips_blob_get_type()
+            })?;
+            let mut data: *const c_void = std::ptr::null();
+            let mut length: u64 = 0;
+            let res = bindings::vips_image_get_blob(self.ctx, name_c_str.as_ptr(), &mut data, &mut length);
+            // the blob is owned by the image, so it is copied and not freed
+            utils::result(
+                res,
+                || {
+                    if data.is_null() {
+                        Vec::new()
+                    } else {
+                        std::slice::from_raw_parts(data as *const u8, length as usize).to_vec()
+                    }
+                },
+                Error::OperationError("VipsImage:get_blob - Could not read field"),
+            )
+        }
+    }
+
+    pub fn set_blob(&mut self, name: &str, value: &[u8]) -> Result<()> {
+        self.make_private()?;
+        unsafe {
+            let name_c_str = utils::new_c_string(name)?;
+            bindings::vips_image_set_blob_copy(
+                self.ctx,
+                name_c_str.as_ptr(),
+                value.as_ptr() as *const c_void,
+                value.len() as u64,
+            );
+            Ok(())
+        }
+    }
+
+    pub fn get_array_int(&self, name: &str) -> Result<Vec<i32>> {
+        unsafe {
+            let name_c_str = utils::new_c_string(name)?;
+            self.check_field(name, &name_c_str, "array of int", |gtype| {
+                gtype == bindings::vips_array_int_get_type()
+            })?;
+            let mut out: *mut i32 = null_mut();
+            let mut size: i32 = 0;
+            let res = bindings::vips_image_get_array_int(self.ctx, name_c_str.as_ptr(), &mut out, &mut size);
+            utils::result(
+                res,
+                || utils::new_int_array(out, size as u64),
+                Error::OperationError("VipsImage:get_array_int - Could not read field"),
+            )
+        }
+    }
+
+    pub fn set_array_int(&mut self, name: &str, value: &[i32]) -> Result<()> {
+        self.make_private()?;
+        unsafe {
+            let name_c_str = utils::new_c_string(name)?;
+            bindings::vips_image_set_array_int(
+                self.ctx,
+                name_c_str.as_ptr(),
+                value.as_ptr(),
+                value.len() as i32,
+            );
+            Ok(())
+        }
+    }
+
+    pub fn get_array_double(&self, name: &str) -> Result<Vec<f64>> {
+        unsafe {
+            let name_c_str = utils::new_c_string(name)?;
+            self.check_field(name, &name_c_str, "array of double", |gtype| {
+                gtype == bindings::vips_array_double_get_type()
+            })?;
+            let mut out: *mut f64 = null_mut();
+            let mut size: i32 = 0;
+            let res = bindings::vips_image_get_array_double(self.ctx, name_c_str.as_ptr(), &mut out, &mut size);
+            utils::result(
+                res,
+                || utils::new_double_array(out, size as u64),
+                Error::OperationError("VipsImage:get_array_double - Could not read field"),
+            )
+        }
+    }
+
+    pub fn set_array_double(&mut self, name: &str, value: &[f64]) -> Result<()> {
+        self.make_private()?;
+        unsafe {
+            let name_c_str = utils::new_c_string(name)?;
+            bindings::vips_image_set_array_double(
+                self.ctx,
+                name_c_str.as_ptr(),
+                value.as_ptr(),
+                value.len() as i32,
+            );
+            Ok(())
+        }
+    }
+
+    pub fn get_image(&self, name: &str) -> Result<VipsImage> {
+        unsafe {
+            let name_c_str = utils::new_c_string(name)?;
+            self.check_field(name, &name_c_str, "image", |gtype| {
+                gtype == bindings::vips_image_get_type()
+            })?;
+            let mut out: *mut bindings::VipsImage = null_mut();
+            let res = bindings::vips_image_get_image(self.ctx, name_c_str.as_ptr(), &mut out);
+            utils::result(
+                res,
+                || VipsImage { ctx: out },
+                Error::OperationError("VipsImage:get_image - Could not read field"),
+            )
+        }
+    }
+
+    pub fn set_image(&mut self, name: &str, value: &VipsImage) -> Result<()> {
+        self.make_private()?;
+        unsafe {
+            let name_c_str = utils::new_c_string(name)?;
+            bindings::vips_image_set_image(self.ctx, name_c_str.as_ptr(), value.ctx);
+            Ok(())
+        }
+    }
+
+    /// names of all the fields of the image, including the header fields like width and height
+    pub fn field_names(&self) -> Vec<String> {
+        unsafe {
+            let fields = bindings::vips_image_get_fields(self.ctx);
+            let mut names = Vec::new();
+            let mut field = fields;
+            while !(*field).is_null() {
+                names.push(CStr::from_ptr(*field).to_string_lossy().into_owned());
+                field = field.add(1);
+            }
+            bindings::g_strfreev(fields);
+            names
+        }
+    }
+
+    /// all the fields of the image with their typed values. fields of a type without a
+    /// matching `MetadataValue` are returned as `MetadataValue::String`, formatted by libvips
+    pub fn fields(&self) -> Result<BTreeMap<String, MetadataValue>> {
+        self.field_names()
+            .into_iter()
+            .map(|name| {
+                let value = self.get_field(&name)?;
+                Ok((name, value))
+            })
+            .collect()
+    }
+
+    pub fn get_field(&self, name: &str) -> Result<MetadataValue> {
+        unsafe {
+            let name_c_str = utils::new_c_string(name)?;
+            let gtype = bindings::vips_image_get_typeof(self.ctx, name_c_str.as_ptr());
+            if gtype == 0 {
+                Err(Error::MissingFieldError(name.to_string()))
+            } else if gtype == G_TYPE_STRING || gtype == bindings::vips_ref_string_get_type() {
+                self.get_string(name).map(MetadataValue::String)
+            } else if gtype == bindings::vips_blob_get_type() {
+                self.get_blob(name).map(MetadataValue::Blob)
+            } else if gtype == bindings::vips_array_int_get_type() {
+                self.get_array_int(name).map(MetadataValue::ArrayInt)
+            } else if gtype == bindings::vips_array_double_get_type() {
+                self.get_array_double(name).map(MetadataValue::ArrayDouble)
+            } else if gtype == bindings::vips_image_get_type() {
+                self.get_image(name).map(MetadataValue::Image)
+            } else if bindings::g_type_fundamental(gtype) == G_TYPE_DOUBLE {
+                self.get_double(name).map(MetadataValue::Double)
+            } else if is_int(gtype) {
+                // enums, flags and booleans are read as their int value
+                self.get_int(name).map(MetadataValue::Int)
+            } else {
+                self.get_as_string(name).map(MetadataValue::String)
+            }
+        }
+    }
+
+    pub fn set_field(&mut self, name: &str, value: &MetadataValue) -> Result<()> {
+        match value {
+            MetadataValue::Int(value) => self.set_int(name, *value),
+            MetadataValue::Double(value) => self.set_double(name, *value),
+            MetadataValue::String(value) => self.set_string(name, value),
+            MetadataValue::Blob(value) => self.set_blob(name, value),
+            MetadataValue::ArrayInt(value) => self.set_array_int(name, value),
+            MetadataValue::ArrayDouble(value) => self.set_array_double(name, value),
+            MetadataValue::Image(value) => self.set_image(name, value),
+        }
+    }
+
+    /// removes the field from the image. returns false if there was no field with this name
+    pub fn remove(&mut self, name: &str) -> Result<bool> {
+        self.make_private()?;
+        unsafe {
+            let name_c_str = utils::new_c_string(name)?;
+            Ok(bindings::vips_image_remove(self.ctx, name_c_str.as_ptr()) != 0)
+        }
+    }
+
+    // a new image reading the pixels of this one, with its own fields. the copy is built outside
+    // of the operation cache, which would return the same image to every caller
+    pub(crate) fn private_copy(&self) -> Result<VipsImage> {
+        let mut operation = utils::VipsOperationWrapper::new("copy", || Error::CopyError)?;
+        operation.set_image("in", self)?;
+        operation.build_uncached()?;
+        operation.get_image("out")
+    }
+
+    // called by the methods and operations modifying an image in place. when clones, images
+    // computed from this one or the operation cache hold a reference to it, it's replaced by a
+    // private copy first, so they don't see the change. the copy reads the pixels of the original
+    // until they are modified: `vips_image_inplace`, called by the `draw_*` operations, renders it
+    // to memory first
+    pub(crate) fn make_private(&mut self) -> Result<()> {
+        // the count is changed atomically by the other holders. a count of 1 means this handle is the
+        // only one, and only a handle can add references, so it can't grow concurrently
+        let ref_count = unsafe {
+            let object = self.ctx as *mut bindings::GObject;
+            bindings::g_atomic_int_get(std::ptr::addr_of!((*object).ref_count) as *const bindings::gint)
+        };
+        if ref_count > 1 {
+            *self = self.private_copy()?;
+        }
+        Ok(())
+    }
+
+    // checks if the field exists and its type is accepted by the getter before calling libvips,
+    // so a missing field or a wrong type are reported with their own errors
+    unsafe fn check_field(
+        &self,
+        name: &str,
+        name_c_str: &CStr,
+        expected: &'static str,
+        accepts: impl Fn(bindings::GType) -> bool,
+    ) -> Result<()> {
+        let gtype = bindings::vips_image_get_typeof(self.ctx, name_c_str.as_ptr());
+        if gtype == 0 {
+            Err(Error::MissingFieldError(name.to_string()))
+        } else if accepts(gtype) {
+            Ok(())
+        } else {
+            Err(Error::FieldTypeError {
+                name: name.to_string(),
+                expected,
+                found: CStr::from_ptr(bindings::g_type_name(gtype))
+                    .to_string_lossy()
+                    .into_owned(),
+            })
+        }
+    }
+}
+
+// the fields `get_int` reads: ints and the types stored as one. doubles would be truncated and the
+// wider integers could overflow
+fn is_int(gtype: bindings::GType) -> bool {
+    let fundamental = unsafe { bindings::g_type_fundamental(gtype) };
+    [G_TYPE_INT, G_TYPE_BOOLEAN, G_TYPE_ENUM, G_TYPE_FLAGS].contains(&fundamental)
+}
+
+// libvips images are reference counted atomically and their operations are thread safe, so
+// ownership can move between threads. Sharing needs `SharedImage`, which keeps the `draw_*`
+// operations and the field setters (the only ones mutating an image) out of reach. A clone of the
+// shared image is another reference to it, which the `draw_*` operations and the setters make
+// private before changing it.
+unsafe impl Send for VipsImage {}
+
+unsafe impl Send for SharedImage {}
+unsafe impl Sync for SharedImage {}
+
+impl SharedImage {
+    // the image isn't Sync, the handle is, as it only gives out `&VipsImage`
+    #[allow(clippy::arc_with_non_send_sync)]
+    pub fn new(image: VipsImage) -> SharedImage {
+        SharedImage { image: Arc::new(image) }
+    }
+
+    /// renders the shared image into a new memory image that no other handle refers to,
+    /// which makes it safe to use with the `draw_*` operations
+    pub fn to_private_copy(&self) -> Result<VipsImage> {
+        VipsImage::image_copy_memory(self.image.private_copy()?)
+    }
+}
+
+impl From<VipsImage> for SharedImage {
+    fn from(image: VipsImage) -> Self {
+        SharedImage::new(image)
+    }
+}
+
+impl std::ops::Deref for SharedImage {
+    type Target = VipsImage;
+
+    fn deref(&self) -> &VipsImage {
+        &self.image
+    }
 }
 
 impl VipsConnection {
@@ -644,6 +1096,72 @@ impl VipsSource {
         }
     }
 
+    /// creates a source reading from any seekable reader, like a file inside an archive or a
+    /// decrypting stream. the reader is called from the libvips worker threads and is dropped
+    /// together with the source
+    pub fn from_reader<R: Read + Seek + Send + 'static>(reader: R) -> Result<Self> {
+        VipsSource::new_custom(Box::new(reader), true)
+    }
+
+    /// creates a source from a reader that can't seek, like a network stream. libvips treats it
+    /// as a pipe and keeps in memory whatever it needs to read twice. its length is only known once
+    /// it is read to the end, so the reads fail as soon as they go past the `max_bytes` of the
+    /// `LoadPolicy`
+    pub fn from_unseekable_reader<R: Read + Send + 'static>(reader: R) -> Result<Self> {
+        let read = Arc::new(AtomicU64::new(0));
+        let source = VipsSource::new_custom(Box::new(Unseekable { reader, read: read.clone() }), false)?;
+        unsafe {
+            bindings::g_object_set_data_full(
+                source.ctx as *mut bindings::GObject,
+                BYTES_READ_KEY.as_ptr() as *const c_char,
+                Arc::into_raw(read) as *mut c_void,
+                Some(release_bytes_read),
+            );
+        }
+        Ok(source)
+    }
+
+    // the bytes read so far from a source created by `from_unseekable_reader`
+    pub(crate) fn bytes_read(&self) -> Option<u64> {
+        unsafe {
+            let read = bindings::g_object_get_data(self.ctx as *mut bindings::GObject, BYTES_READ_KEY.as_ptr() as *const c_char)
+                as *const AtomicU64;
+            read.as_ref().map(|read| read.load(Ordering::SeqCst))
+        }
+    }
+
+    fn new_custom(stream: Box<dyn SourceStream>, seekable: bool) -> Result<Self> {
+        unsafe {
+            let read_signal = utils::new_c_string("read")?;
+            let seek_signal = utils::new_c_string("seek")?;
+            let res = bindings::vips_source_custom_new();
+            let source = vips_source_result(
+                res as *mut bindings::VipsSource,
+                Error::InitializationError("VipsSource:new_custom - Could not initialise VipsSource"),
+            )?;
+            // the stream is shared by both handlers and freed when the read handler is disconnected,
+            // which happens when the source is finalized
+            let stream = Box::into_raw(Box::new(stream)) as *mut c_void;
+            utils::signal_connect(
+                res as *mut c_void,
+                &read_signal,
+                Some(std::mem::transmute::<SourceReadFn, unsafe extern "C" fn()>(source_read)),
+                stream,
+                Some(free_source_stream),
+            );
+            if seekable {
+                utils::signal_connect(
+                    res as *mut c_void,
+                    &seek_signal,
+                    Some(std::mem::transmute::<SourceSeekFn, unsafe extern "C" fn()>(source_seek)),
+                    stream,
+                    None,
+                );
+            }
+            Ok(source)
+        }
+    }
+
     pub fn new_from_options(option_str: &str) -> Result<Self> {
         unsafe {
             let options = utils::new_c_string(option_str)?;
@@ -692,7 +1210,7 @@ impl VipsSource {
                     Vec::from_raw_parts(bytes as *mut u8, result as usize, result as usize);
                 Ok(buffer)
             } else {
-                Err(Error::OperationError("VipsSource:read - Error on vips read"))
+                Err(utils::vips_error(Error::OperationError("VipsSource:read - Error on vips read")))
             }
         }
     }
@@ -705,7 +1223,7 @@ impl VipsSource {
         unsafe {
             let result = bindings::vips_source_seek(self.ctx, offset, whence);
             if result == -1 {
-                Err(Error::OperationError("VipsSource:is_mappable - Error on vips seek"))
+                Err(utils::vips_error(Error::OperationError("VipsSource:is_mappable - Error on vips seek")))
             } else {
                 Ok(result)
             }
@@ -716,7 +1234,7 @@ impl VipsSource {
         unsafe {
             let result = bindings::vips_source_rewind(self.ctx);
             if result == -1 {
-                Err(Error::OperationError("VipsSource:rewind - Error on vips rewind"))
+                Err(utils::vips_error(Error::OperationError("VipsSource:rewind - Error on vips rewind")))
             } else {
                 Ok(())
             }
@@ -727,7 +1245,7 @@ impl VipsSource {
         unsafe {
             let result = bindings::vips_source_length(self.ctx);
             if result == -1 {
-                Err(Error::OperationError("VipsSource:length - Error on vips length"))
+                Err(utils::vips_error(Error::OperationError("VipsSource:length - Error on vips length")))
             } else {
                 Ok(result)
             }
@@ -795,6 +1313,66 @@ impl VipsTarget {
         }
     }
 
+    /// creates a target writing to any writer, like an HTTP response body, a hasher or a compressor.
+    /// the writer is flushed when the target ends and dropped together with the target.
+    /// savers which go back in their output, like tiffsave, need `from_seekable_writer` instead
+    pub fn from_writer<W: Write + Send + 'static>(writer: W) -> Result<Self> {
+        VipsTarget::new_custom(Box::new(WriteOnly(writer)), false)
+    }
+
+    /// creates a target writing to a writer which can also be read back and seeked, like a file or a `Cursor<Vec<u8>>`
+    pub fn from_seekable_writer<W: Read + Write + Seek + Send + 'static>(writer: W) -> Result<Self> {
+        VipsTarget::new_custom(Box::new(writer), true)
+    }
+
+    fn new_custom(stream: Box<dyn TargetStream>, seekable: bool) -> Result<Self> {
+        unsafe {
+            let write_signal = utils::new_c_string("write")?;
+            let end_signal = utils::new_c_string("end")?;
+            let read_signal = utils::new_c_string("read")?;
+            let seek_signal = utils::new_c_string("seek")?;
+            let res = bindings::vips_target_custom_new();
+            let target = vips_target_result(
+                res as *mut bindings::VipsTarget,
+                Error::InitializationError("VipsTarget:new_custom - Could not initialise VipsTarget"),
+            )?;
+            // the stream is shared by all handlers and freed when the write handler is disconnected,
+            // which happens when the target is finalized
+            let stream = Box::into_raw(Box::new(stream)) as *mut c_void;
+            utils::signal_connect(
+                res as *mut c_void,
+                &write_signal,
+                Some(std::mem::transmute::<TargetWriteFn, unsafe extern "C" fn()>(target_write)),
+                stream,
+                Some(free_target_stream),
+            );
+            utils::signal_connect(
+                res as *mut c_void,
+                &end_signal,
+                Some(std::mem::transmute::<TargetEndFn, unsafe extern "C" fn()>(target_end)),
+                stream,
+                None,
+            );
+            if seekable {
+                utils::signal_connect(
+                    res as *mut c_void,
+                    &read_signal,
+                    Some(std::mem::transmute::<TargetReadFn, unsafe extern "C" fn()>(target_read)),
+                    stream,
+                    None,
+                );
+                utils::signal_connect(
+                    res as *mut c_void,
+                    &seek_signal,
+                    Some(std::mem::transmute::<TargetSeekFn, unsafe extern "C" fn()>(target_seek)),
+                    stream,
+                    None,
+                );
+            }
+            Ok(target)
+        }
+    }
+
     pub fn write(&mut self, buffer: &[u8]) -> Result<()> {
         unsafe {
             let res = bindings::vips_target_write(
@@ -803,7 +1381,7 @@ impl VipsTarget {
                 buffer.len() as u64,
             );
             if res == -1 {
-                Err(Error::OperationError("VipsTarget:write - Could not write to buffer"))
+                Err(utils::vips_error(Error::OperationError("VipsTarget:write - Could not write to buffer")))
             } else {
                 Ok(())
             }
@@ -816,11 +1394,42 @@ impl VipsTarget {
         }
     }
 
+    /// ends a target created with `new_to_memory` and returns everything written to it.
+    /// savers end their target when they are done, in which case the bytes are taken from the target blob
+    pub fn steal(self) -> Result<Vec<u8>> {
+        unsafe {
+            let target = &*self.ctx;
+            if target.memory == 0 {
+                return Err(Error::OperationError("VipsTarget:steal - Target does not write to memory"));
+            }
+            if target.ended != 0 {
+                if target.blob.is_null() {
+                    return Err(Error::OperationError("VipsTarget:steal - Target has no memory buffer"));
+                }
+                let mut size: u64 = 0;
+                let bytes = bindings::vips_blob_get(target.blob, &mut size);
+                if bytes.is_null() {
+                    return Ok(Vec::new());
+                }
+                return Ok(std::slice::from_raw_parts(bytes as *const u8, size as usize).to_vec());
+            }
+            let mut size: u64 = 0;
+            let bytes = bindings::vips_target_steal(self.ctx, &mut size);
+            if bytes.is_null() {
+                Err(utils::vips_error(Error::OperationError("VipsTarget:steal - Could not end target")))
+            } else {
+                let buf = std::slice::from_raw_parts(bytes, size as usize).to_vec();
+                bindings::g_free(bytes as *mut c_void);
+                Ok(buf)
+            }
+        }
+    }
+
     pub fn putc(&mut self, ch: char) -> Result<()> {
         unsafe {
             let res = bindings::vips_target_putc(self.ctx, ch as i32);
             if res == -1 {
-                Err(Error::OperationError("VipsTarget:putc - Could not write to buffer"))
+                Err(utils::vips_error(Error::OperationError("VipsTarget:putc - Could not write to buffer")))
             } else {
                 Ok(())
             }
@@ -832,7 +1441,7 @@ impl VipsTarget {
             let cstr = CString::new(text).map_err(|_| Error::OperationError("VipsTarget:writes - Cannot initialize C string"))?;
             let res = bindings::vips_target_writes(self.ctx, cstr.as_ptr());
             if res == -1 {
-                Err(Error::OperationError("VipsTarget:puts - Could not write to buffer"))
+                Err(utils::vips_error(Error::OperationError("VipsTarget:puts - Could not write to buffer")))
             } else {
                 Ok(())
             }
@@ -844,7 +1453,7 @@ impl VipsTarget {
             let cstr = CString::new(text).map_err(|_| Error::OperationError("VipsTarget:write_amp - Cannot initialize C string"))?;
             let res = bindings::vips_target_write_amp(self.ctx, cstr.as_ptr());
             if res == -1 {
-                Err(Error::OperationError("VipsTarget:write_amp - Could not write to buffer"))
+                Err(utils::vips_error(Error::OperationError("VipsTarget:write_amp - Could not write to buffer")))
             } else {
                 Ok(())
             }
@@ -852,17 +1461,218 @@ impl VipsTarget {
     }
 }
 
+// a reader backing a custom VipsSource
+trait SourceStream: Read + Seek + Send {}
+
+impl<T: Read + Seek + Send> SourceStream for T {}
+
+// lets a reader that can't seek be stored as a SourceStream. its seek handler is never connected.
+// the bytes read are counted, shared with the source, against the byte limit of the load policy
+struct Unseekable<R> {
+    reader: R,
+    read: Arc<AtomicU64>,
+}
+
+impl<R: Read> Read for Unseekable<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.reader.read(buf)?;
+        let total = self.read.fetch_add(read as u64, Ordering::SeqCst) + read as u64;
+        crate::policy::load_policy()
+            .check_bytes(total)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e.to_string()))?;
+        Ok(read)
+    }
+}
+
+impl<R> Seek for Unseekable<R> {
+    fn seek(&mut self, _: SeekFrom) -> std::io::Result<u64> {
+        Err(std::io::Error::from(std::io::ErrorKind::Unsupported))
+    }
+}
+
+type SourceReadFn = unsafe extern "C" fn(*mut bindings::VipsSourceCustom, *mut c_void, i64, *mut c_void) -> i64;
+type SourceSeekFn = unsafe extern "C" fn(*mut bindings::VipsSourceCustom, i64, c_int, *mut c_void) -> i64;
+
+// handlers of the VipsSourceCustom signals. they return -1 on errors and must not unwind into C
+unsafe extern "C" fn source_read(
+    _: *mut bindings::VipsSourceCustom,
+    buffer: *mut c_void,
+    length: i64,
+    stream: *mut c_void,
+) -> i64 {
+    if length <= 0 {
+        return 0;
+    }
+    let stream = &mut *(stream as *mut Box<dyn SourceStream>);
+    let buffer = std::slice::from_raw_parts_mut(buffer as *mut u8, length as usize);
+    let result = panic::catch_unwind(AssertUnwindSafe(|| loop {
+        match stream.read(buffer) {
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            result => break result,
+        }
+    }));
+    match result {
+        Ok(Ok(read)) => read as i64,
+        _ => -1,
+    }
+}
+
+unsafe extern "C" fn source_seek(
+    _: *mut bindings::VipsSourceCustom,
+    offset: i64,
+    whence: c_int,
+    stream: *mut c_void,
+) -> i64 {
+    let stream = &mut *(stream as *mut Box<dyn SourceStream>);
+    let position = match whence as u32 {
+        bindings::SEEK_SET => SeekFrom::Start(offset as u64),
+        bindings::SEEK_CUR => SeekFrom::Current(offset),
+        bindings::SEEK_END => SeekFrom::End(offset),
+        _ => return -1,
+    };
+    match panic::catch_unwind(AssertUnwindSafe(|| stream.seek(position))) {
+        Ok(Ok(position)) => position as i64,
+        _ => -1,
+    }
+}
+
+unsafe extern "C" fn free_source_stream(stream: *mut c_void, _: *mut bindings::GClosure) {
+    drop(Box::from_raw(stream as *mut Box<dyn SourceStream>));
+}
+
+const BYTES_READ_KEY: &[u8] = b"libvips-rust-bytes-read\0";
+
+unsafe extern "C" fn release_bytes_read(read: *mut c_void) {
+    drop(Arc::from_raw(read as *const AtomicU64));
+}
+
+// a writer backing a custom VipsTarget
+trait TargetStream: Read + Write + Seek + Send {}
+
+impl<T: Read + Write + Seek + Send> TargetStream for T {}
+
+// lets a plain writer be stored as a TargetStream. its read and seek handlers are never connected
+struct WriteOnly<W>(W);
+
+impl<W> Read for WriteOnly<W> {
+    fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::from(std::io::ErrorKind::Unsupported))
+    }
+}
+
+impl<W: Write> Write for WriteOnly<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.flush()
+    }
+}
+
+impl<W> Seek for WriteOnly<W> {
+    fn seek(&mut self, _: SeekFrom) -> std::io::Result<u64> {
+        Err(std::io::Error::from(std::io::ErrorKind::Unsupported))
+    }
+}
+
+type TargetWriteFn = unsafe extern "C" fn(*mut bindings::VipsTargetCustom, *const c_void, i64, *mut c_void) -> i64;
+type TargetReadFn = unsafe extern "C" fn(*mut bindings::VipsTargetCustom, *mut c_void, i64, *mut c_void) -> i64;
+type TargetSeekFn = unsafe extern "C" fn(*mut bindings::VipsTargetCustom, i64, c_int, *mut c_void) -> i64;
+type TargetEndFn = unsafe extern "C" fn(*mut bindings::VipsTargetCustom, *mut c_void) -> c_int;
+
+// handlers of the VipsTargetCustom signals. they return -1 on errors and must not unwind into C
+unsafe extern "C" fn target_write(
+    _: *mut bindings::VipsTargetCustom,
+    data: *const c_void,
+    length: i64,
+    stream: *mut c_void,
+) -> i64 {
+    if length <= 0 {
+        return 0;
+    }
+    let stream = &mut *(stream as *mut Box<dyn TargetStream>);
+    let data = std::slice::from_raw_parts(data as *const u8, length as usize);
+    match panic::catch_unwind(AssertUnwindSafe(|| stream.write_all(data))) {
+        Ok(Ok(())) => length,
+        _ => -1,
+    }
+}
+
+unsafe extern "C" fn target_read(
+    _: *mut bindings::VipsTargetCustom,
+    buffer: *mut c_void,
+    length: i64,
+    stream: *mut c_void,
+) -> i64 {
+    if length <= 0 {
+        return 0;
+    }
+    let stream = &mut *(stream as *mut Box<dyn TargetStream>);
+    let buffer = std::slice::from_raw_parts_mut(buffer as *mut u8, length as usize);
+    let result = panic::catch_unwind(AssertUnwindSafe(|| loop {
+        match stream.read(buffer) {
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            result => break result,
+        }
+    }));
+    match result {
+        Ok(Ok(read)) => read as i64,
+        _ => -1,
+    }
+}
+
+unsafe extern "C" fn target_seek(
+    _: *mut bindings::VipsTargetCustom,
+    offset: i64,
+    whence: c_int,
+    stream: *mut c_void,
+) -> i64 {
+    let stream = &mut *(stream as *mut Box<dyn TargetStream>);
+    let position = match whence as u32 {
+        bindings::SEEK_SET => SeekFrom::Start(offset as u64),
+        bindings::SEEK_CUR => SeekFrom::Current(offset),
+        bindings::SEEK_END => SeekFrom::End(offset),
+        _ => return -1,
+    };
+    match panic::catch_unwind(AssertUnwindSafe(|| stream.seek(position))) {
+        Ok(Ok(position)) => position as i64,
+        _ => -1,
+    }
+}
+
+unsafe extern "C" fn target_end(_: *mut bindings::VipsTargetCustom, stream: *mut c_void) -> c_int {
+    let stream = &mut *(stream as *mut Box<dyn TargetStream>);
+    match panic::catch_unwind(AssertUnwindSafe(|| stream.flush())) {
+        Ok(Ok(())) => 0,
+        _ => -1,
+    }
+}
+
+unsafe extern "C" fn free_target_stream(stream: *mut c_void, _: *mut bindings::GClosure) {
+    drop(Box::from_raw(stream as *mut Box<dyn TargetStream>));
+}
+
 unsafe fn vips_image_result(res: *mut bindings::VipsImage, err: Error) -> Result<VipsImage> {
     if res.is_null() {
-        Err(err)
+        Err(utils::vips_error(err))
     } else {
         Ok(VipsImage { ctx: res })
     }
 }
 
+// vips_foreign_find_load takes the filename without the load options
+unsafe fn find_load_file(filename: &str) -> *const c_char {
+    let filename = filename.split('[').next().unwrap_or_default();
+    match utils::new_c_string(filename) {
+        Ok(f) => bindings::vips_foreign_find_load(f.as_ptr()),
+        Err(_) => null_mut(),
+    }
+}
+
 unsafe fn vips_source_result(res: *mut bindings::VipsSource, err: Error) -> Result<VipsSource> {
     if res.is_null() {
-        Err(err)
+        Err(utils::vips_error(err))
     } else {
         Ok(VipsSource { ctx: res })
     }
@@ -870,7 +1680,7 @@ unsafe fn vips_source_result(res: *mut bindings::VipsSource, err: Error) -> Resu
 
 unsafe fn vips_target_result(res: *mut bindings::VipsTarget, err: Error) -> Result<VipsTarget> {
     if res.is_null() {
-        Err(err)
+        Err(utils::vips_error(err))
     } else {
         Ok(VipsTarget { ctx: res })
     }
@@ -879,26 +1689,23 @@ unsafe fn vips_target_result(res: *mut bindings::VipsTarget, err: Error) -> Resu
 impl VipsInterpolate {
     /// defaults to vips_interpolate_nearest_static
     pub fn new() -> VipsInterpolate {
-        unsafe {
-            VipsInterpolate {
-                ctx: bindings::vips_interpolate_nearest_static(),
-            }
-        }
+        VipsInterpolate::new_from_neasest_static()
     }
 
+    // the static interpolators are owned by libvips, so take our own reference to balance the unref on drop
     pub fn new_from_neasest_static() -> VipsInterpolate {
         unsafe {
-            VipsInterpolate {
-                ctx: bindings::vips_interpolate_nearest_static(),
-            }
+            let ctx = bindings::vips_interpolate_nearest_static();
+            bindings::g_object_ref(ctx as *mut c_void);
+            VipsInterpolate { ctx }
         }
     }
 
     pub fn new_from_bilinear_static() -> VipsInterpolate {
         unsafe {
-            VipsInterpolate {
-                ctx: bindings::vips_interpolate_bilinear_static(),
-            }
+            let ctx = bindings::vips_interpolate_bilinear_static();
+            bindings::g_object_ref(ctx as *mut c_void);
+            VipsInterpolate { ctx }
         }
     }
 
@@ -925,6 +1732,75 @@ impl VipsInterpolate {
     }
 }
 
+// all of the wrapped types are reference counted by libvips, so a clone only takes a new reference
+// to the same object instead of copying the pointer (which would unref it twice on drop). the
+// methods changing an image make it private first, as long as other references remain
+impl Clone for VipsImage {
+    fn clone(&self) -> Self {
+        unsafe {
+            if !self.ctx.is_null() {
+                bindings::g_object_ref(self.ctx as *mut c_void);
+            }
+        }
+        VipsImage { ctx: self.ctx }
+    }
+}
+
+impl Clone for VipsInterpolate {
+    fn clone(&self) -> Self {
+        unsafe {
+            if !self.ctx.is_null() {
+                bindings::g_object_ref(self.ctx as *mut c_void);
+            }
+        }
+        VipsInterpolate { ctx: self.ctx }
+    }
+}
+
+impl Clone for VipsBlob {
+    fn clone(&self) -> Self {
+        unsafe {
+            if !self.ctx.is_null() {
+                bindings::vips_area_copy(self.ctx as *mut bindings::VipsArea);
+            }
+        }
+        VipsBlob { ctx: self.ctx }
+    }
+}
+
+impl Clone for VipsConnection {
+    fn clone(&self) -> Self {
+        unsafe {
+            if !self.ctx.is_null() {
+                bindings::g_object_ref(self.ctx as *mut c_void);
+            }
+        }
+        VipsConnection { ctx: self.ctx }
+    }
+}
+
+impl Clone for VipsSource {
+    fn clone(&self) -> Self {
+        unsafe {
+            if !self.ctx.is_null() {
+                bindings::g_object_ref(self.ctx as *mut c_void);
+            }
+        }
+        VipsSource { ctx: self.ctx }
+    }
+}
+
+impl Clone for VipsTarget {
+    fn clone(&self) -> Self {
+        unsafe {
+            if !self.ctx.is_null() {
+                bindings::g_object_ref(self.ctx as *mut c_void);
+            }
+        }
+        VipsTarget { ctx: self.ctx }
+    }
+}
+
 impl Drop for VipsImage {
     fn drop(&mut self) {
         unsafe {
@@ -949,7 +1825,7 @@ impl Drop for VipsBlob {
     fn drop(&mut self) {
         unsafe {
             if !self.ctx.is_null() {
-                bindings::g_object_unref(self.ctx as *mut c_void);
+                bindings::vips_area_unref(self.ctx as *mut bindings::VipsArea);
             }
         }
     }
@@ -988,9 +1864,14 @@ impl Drop for VipsTarget {
 impl Into<Vec<u8>> for VipsBlob {
     fn into(self) -> Vec<u8> {
         unsafe {
+            // the bytes are owned by the blob and freed when it is dropped, so they have to be copied
             let mut size: u64 = 0;
             let bytes = bindings::vips_blob_get(self.ctx, &mut size);
-            Vec::from_raw_parts(bytes as *mut u8, size as usize, size as usize)
+            if bytes.is_null() {
+                Vec::new()
+            } else {
+                std::slice::from_raw_parts(bytes as *const u8, size as usize).to_vec()
+            }
         }
     }
 }
@@ -0,0 +1,445 @@
+// (c) Copyright 2019-2026 OLX
+//! Lists the operations, loaders and savers of the libvips the application runs against, which
+//! can differ from the one the bindings were generated from
+use crate::bindings;
+use crate::error::Error;
+use crate::utils;
+use crate::utils::{
+    G_TYPE_BOOLEAN, G_TYPE_DOUBLE, G_TYPE_ENUM, G_TYPE_FLAGS, G_TYPE_INT, G_TYPE_STRING,
+    G_TYPE_UINT64,
+};
+use crate::ArgumentValue;
+use crate::Result;
+use crate::VipsSource;
+
+use std::ffi::{c_void, CStr};
+use std::os::raw::c_char;
+use std::ptr::null_mut;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArgumentDirection {
+    Input,
+    Output,
+    /// an input image the operation draws on
+    Modify,
+}
+
+#[derive(Debug, Clone)]
+pub struct ArgumentInfo {
+    pub name: String,
+    pub description: String,
+    /// the name of the GType of the argument, e.g. `VipsImage`, `gint` or `VipsInterpretation`
+    pub type_name: String,
+    pub direction: ArgumentDirection,
+    pub required: bool,
+    /// enum defaults are given by the nickname of the value, flags defaults as an int
+    pub default: Option<ArgumentValue>,
+    /// the minimum and the maximum of int, uint64 and double arguments
+    pub range: Option<(
+        ArgumentValue,
+        ArgumentValue,
+    )>,
+    /// the nicknames of the values of enum and flags arguments
+    pub values: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct OperationInfo {
+    pub nickname: String,
+    pub description: String,
+    /// the name of the GType of the operation, e.g. `VipsForeignLoadJpegFile`
+    pub type_name: String,
+    /// the arguments in the order libvips declares them
+    pub arguments: Vec<ArgumentInfo>,
+}
+
+/// the nicknames of all the operations, skipping the abstract and the deprecated ones
+pub fn operations() -> Vec<String> {
+    unsafe {
+        utils::concrete_types(bindings::vips_operation_get_type())
+            .into_iter()
+            .filter(|gtype| {
+                utils::operation_class_flags(*gtype)
+                    & bindings::VipsOperationFlags_VIPS_OPERATION_DEPRECATED
+                    == 0
+            })
+            .filter_map(|gtype| to_string(bindings::vips_nickname_find(gtype)))
+            .collect()
+    }
+}
+
+/// the arguments of an operation, skipping the deprecated ones
+pub fn operation(nickname: &str) -> Result<OperationInfo> {
+    unsafe {
+        let operation = utils::VipsOperationWrapper::new(
+            nickname,
+            || Error::OperationError("introspection:operation - Could not find operation"),
+        )
+        .map_err(|e| {
+            utils::with_operation(
+                e,
+                nickname,
+            )
+        })?;
+        let object = operation.object();
+        let class = (*object)
+            .parent_instance
+            .g_type_instance
+            .g_class;
+        let mut arguments: Vec<ArgumentInfo> = Vec::new();
+        bindings::vips_argument_map(
+            object,
+            Some(collect_argument),
+            &mut arguments as *mut Vec<ArgumentInfo> as *mut c_void,
+            null_mut(),
+        );
+        Ok(
+            OperationInfo {
+                nickname: nickname.to_string(),
+                description: to_string((*(class as *mut bindings::VipsObjectClass)).description)
+                    .unwrap_or_default(),
+                type_name: to_string(bindings::g_type_name((*class).g_type)).unwrap_or_default(),
+                arguments,
+            },
+        )
+    }
+}
+
+/// the nickname of the loader for the file, found by sniffing its contents, e.g. `jpegload`
+pub fn find_load(filename: &str) -> Option<String> {
+    unsafe {
+        let filename_c_str = utils::new_c_string(filename).ok()?;
+        nickname_of(bindings::vips_foreign_find_load(filename_c_str.as_ptr()))
+    }
+}
+
+/// the nickname of the saver for the file, found by its suffix, e.g. `jpegsave`
+pub fn find_save(filename: &str) -> Option<String> {
+    unsafe {
+        let filename_c_str = utils::new_c_string(filename).ok()?;
+        nickname_of(bindings::vips_foreign_find_save(filename_c_str.as_ptr()))
+    }
+}
+
+/// The format of an image, as told by the loader libvips picks for it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImageFormat {
+    Jpeg,
+    Png,
+    Webp,
+    Gif,
+    Tiff,
+    Heif,
+    Avif,
+    Svg,
+    Pdf,
+    Jxl,
+    Jp2k,
+    Magick,
+    Openslide,
+    Fits,
+    Nifti,
+    Mat,
+    Analyze,
+    Rad,
+    Ppm,
+    Csv,
+    Matrix,
+    Vips,
+    /// a loader without a variant here, e.g. from a newer libvips or a plugin
+    Other,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DetectedFormat {
+    pub format: ImageFormat,
+    /// the nickname of the loader, e.g. `jpegload_buffer`
+    pub loader: String,
+}
+
+/// the format of the image in the buffer, or `None` when no loader supports it
+pub fn detect_format(buffer: &[u8]) -> Option<DetectedFormat> {
+    unsafe {
+        let loader = nickname_of(
+            bindings::vips_foreign_find_load_buffer(
+                buffer.as_ptr() as *const c_void,
+                buffer.len() as u64,
+            ),
+        )?;
+        Some(
+            detected_format(
+                loader,
+                buffer.get(..12),
+            ),
+        )
+    }
+}
+
+/// the format of the image in the source, or `None` when no loader supports it. the source is
+/// rewound, so it can be loaded afterwards, and `None` is also returned when it can't be
+pub fn detect_format_source(source: &VipsSource) -> Option<DetectedFormat> {
+    unsafe {
+        let loader = nickname_of(bindings::vips_foreign_find_load_source(source.ctx))?;
+        let header = bindings::vips_source_sniff(
+            source.ctx,
+            12,
+        );
+        // too short to sniff, which isn't an error of the detection
+        let header = (!header.is_null()).then(|| std::slice::from_raw_parts(header, 12));
+        let detected = detected_format(
+            loader,
+            header,
+        );
+        // finding the loader and sniffing read from the source, which is put back at its start for
+        // the load that follows
+        if bindings::vips_source_rewind(source.ctx) != 0 {
+            return None;
+        }
+        Some(detected)
+    }
+}
+
+/// the format of the image in the file, found by sniffing its contents, or `None` when no loader
+/// supports it
+pub fn detect_format_file(filename: &str) -> Option<DetectedFormat> {
+    let loader = find_load(filename)?;
+    let mut header = [0; 12];
+    let header = std::fs::File::open(filename)
+        .and_then(
+            |mut file| {
+                std::io::Read::read_exact(
+                    &mut file,
+                    &mut header,
+                )
+            },
+        )
+        .ok()
+        .map(|_| &header[..]);
+    Some(
+        detected_format(
+            loader,
+            header,
+        ),
+    )
+}
+
+/// the file suffixes the savers support, e.g. `.jpg`
+pub fn suffixes() -> Vec<String> {
+    unsafe {
+        let suffixes = bindings::vips_foreign_get_suffixes();
+        if suffixes.is_null() {
+            return Vec::new();
+        }
+        let mut result = Vec::new();
+        let mut suffix = suffixes;
+        while !(*suffix).is_null() {
+            result.push(
+                CStr::from_ptr(*suffix)
+                    .to_string_lossy()
+                    .into_owned(),
+            );
+            suffix = suffix.add(1);
+        }
+        bindings::g_strfreev(suffixes);
+        result
+    }
+}
+
+unsafe extern "C" fn collect_argument(
+    _object: *mut bindings::VipsObject,
+    pspec: *mut bindings::GParamSpec,
+    argument_class: *mut bindings::VipsArgumentClass,
+    _argument_instance: *mut bindings::VipsArgumentInstance,
+    a: *mut c_void,
+    _b: *mut c_void,
+) -> *mut c_void {
+    let flags = (*argument_class).flags;
+    if flags & bindings::VipsArgumentFlags_VIPS_ARGUMENT_DEPRECATED != 0
+        || flags & bindings::VipsArgumentFlags_VIPS_ARGUMENT_CONSTRUCT == 0
+    {
+        return null_mut();
+    }
+    let direction = if flags & bindings::VipsArgumentFlags_VIPS_ARGUMENT_INPUT == 0 {
+        ArgumentDirection::Output
+    } else if flags & bindings::VipsArgumentFlags_VIPS_ARGUMENT_MODIFY != 0 {
+        ArgumentDirection::Modify
+    } else {
+        ArgumentDirection::Input
+    };
+    let value_type = (*pspec).value_type;
+    let (default, range, values) = match bindings::g_type_fundamental(value_type) {
+        G_TYPE_BOOLEAN => {
+            let pspec = pspec as *mut bindings::GParamSpecBoolean;
+            (
+                Some(ArgumentValue::Bool((*pspec).default_value != 0)),
+                None,
+                Vec::new(),
+            )
+        }
+        G_TYPE_INT => {
+            let pspec = pspec as *mut bindings::GParamSpecInt;
+            (
+                Some(ArgumentValue::Int((*pspec).default_value)),
+                Some((
+                    ArgumentValue::Int((*pspec).minimum),
+                    ArgumentValue::Int((*pspec).maximum),
+                )),
+                Vec::new(),
+            )
+        }
+        G_TYPE_UINT64 => {
+            let pspec = pspec as *mut bindings::GParamSpecUInt64;
+            (
+                Some(ArgumentValue::UInt64((*pspec).default_value)),
+                Some((
+                    ArgumentValue::UInt64((*pspec).minimum),
+                    ArgumentValue::UInt64((*pspec).maximum),
+                )),
+                Vec::new(),
+            )
+        }
+        G_TYPE_DOUBLE => {
+            let pspec = pspec as *mut bindings::GParamSpecDouble;
+            (
+                Some(ArgumentValue::Double((*pspec).default_value)),
+                Some((
+                    ArgumentValue::Double((*pspec).minimum),
+                    ArgumentValue::Double((*pspec).maximum),
+                )),
+                Vec::new(),
+            )
+        }
+        G_TYPE_ENUM => {
+            let pspec = pspec as *mut bindings::GParamSpecEnum;
+            let enum_class = (*pspec).enum_class;
+            let enum_values = std::slice::from_raw_parts(
+                (*enum_class).values,
+                (*enum_class).n_values as usize,
+            );
+            let default = enum_values
+                .iter()
+                .find(|value| value.value == (*pspec).default_value)
+                .and_then(|value| to_string(value.value_nick))
+                .map(ArgumentValue::String);
+            let values = enum_values
+                .iter()
+                .filter_map(|value| to_string(value.value_nick))
+                .collect();
+            (
+                default,
+                None,
+                values,
+            )
+        }
+        G_TYPE_FLAGS => {
+            let pspec = pspec as *mut bindings::GParamSpecFlags;
+            let flags_class = (*pspec).flags_class;
+            let values = std::slice::from_raw_parts(
+                (*flags_class).values,
+                (*flags_class).n_values as usize,
+            )
+            .iter()
+            .filter_map(|value| to_string(value.value_nick))
+            .collect();
+            (
+                Some(ArgumentValue::Int((*pspec).default_value as i32)),
+                None,
+                values,
+            )
+        }
+        G_TYPE_STRING => {
+            let pspec = pspec as *mut bindings::GParamSpecString;
+            (
+                to_string((*pspec).default_value).map(ArgumentValue::String),
+                None,
+                Vec::new(),
+            )
+        }
+        _ => (
+            None,
+            None,
+            Vec::new(),
+        ),
+    };
+    (*(a as *mut Vec<ArgumentInfo>)).push(
+        ArgumentInfo {
+            name: to_string((*pspec).name).unwrap_or_default(),
+            description: to_string(bindings::g_param_spec_get_blurb(pspec)).unwrap_or_default(),
+            type_name: to_string(bindings::g_type_name(value_type)).unwrap_or_default(),
+            direction,
+            required: flags & bindings::VipsArgumentFlags_VIPS_ARGUMENT_REQUIRED != 0,
+            default,
+            range,
+            values,
+        },
+    );
+    null_mut()
+}
+
+// heifload loads AVIF as well, which is told apart by the brand of the ftyp box
+fn detected_format(loader: String, header: Option<&[u8]>) -> DetectedFormat {
+    let format = match loader
+        .split("load")
+        .next()
+        .unwrap_or_default()
+    {
+        "jpeg" => ImageFormat::Jpeg,
+        "png" => ImageFormat::Png,
+        "webp" => ImageFormat::Webp,
+        "gif" => ImageFormat::Gif,
+        "tiff" => ImageFormat::Tiff,
+        "heif" => match header {
+            Some(header)
+                if &header[4..8] == b"ftyp"
+                    && matches!(
+                        &header[8..12],
+                        b"avif" | b"avis"
+                    ) =>
+            {
+                ImageFormat::Avif
+            }
+            _ => ImageFormat::Heif,
+        },
+        "svg" => ImageFormat::Svg,
+        "pdf" => ImageFormat::Pdf,
+        "jxl" => ImageFormat::Jxl,
+        "jp2k" => ImageFormat::Jp2k,
+        "magick" | "magick7" => ImageFormat::Magick,
+        "openslide" => ImageFormat::Openslide,
+        "fits" => ImageFormat::Fits,
+        "nifti" => ImageFormat::Nifti,
+        "mat" => ImageFormat::Mat,
+        "analyze" => ImageFormat::Analyze,
+        "rad" => ImageFormat::Rad,
+        "ppm" => ImageFormat::Ppm,
+        "csv" => ImageFormat::Csv,
+        "matrix" => ImageFormat::Matrix,
+        "vips" => ImageFormat::Vips,
+        _ => ImageFormat::Other,
+    };
+    DetectedFormat {
+        format,
+        loader,
+    }
+}
+
+// the find functions return the type name of the loader or saver
+unsafe fn nickname_of(type_name: *const c_char) -> Option<String> {
+    if type_name.is_null() {
+        // no loader or saver found, which is reported as `None`
+        return None;
+    }
+    to_string(bindings::vips_nickname_find(bindings::g_type_from_name(type_name)))
+}
+
+unsafe fn to_string(string: *const c_char) -> Option<String> {
+    if string.is_null() {
+        None
+    } else {
+        Some(
+            CStr::from_ptr(string)
+                .to_string_lossy()
+                .into_owned(),
+        )
+    }
+}
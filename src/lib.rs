@@ -8,16 +8,27 @@
 extern crate num_derive;
 extern crate num_traits;
 
+mod animation;
 pub mod bindings;
 pub mod error;
+pub mod introspection;
 mod image;
 pub mod ops;
+mod operation;
+mod policy;
+mod progress;
+mod region;
 mod utils;
 
 use error::Error;
 use std::ffi::*;
 
+pub use animation::*;
 pub use image::*;
+pub use operation::*;
+pub use policy::*;
+pub use progress::*;
+pub use region::*;
 
 pub type Result<T> = std::result::Result<T, error::Error>;
 
@@ -211,6 +222,67 @@ impl VipsApp {
             bindings::vips_pipe_read_limit_set(limit);
         }
     }
+
+    /// blocks (or unblocks) the operations libvips flags as untrusted, like the loaders for
+    /// magick, matlab and fits files. calls of a blocked operation fail with `Error::Blocked`
+    pub fn block_untrusted_set(&self, state: bool) {
+        unsafe {
+            bindings::vips_block_untrusted_set(if state { 1 } else { 0 });
+        }
+    }
+
+    /// blocks (or unblocks) an operation and the ones derived from it, given by nickname or type
+    /// name, e.g. `"svgload"` or `"VipsForeignLoadSvg"` for all the svg loaders
+    pub fn operation_block_set(&self, name: &str, state: bool) -> Result<()> {
+        unsafe {
+            if !operation_exists(name)? {
+                return Err(Error::OperationError("VipsApp:operation_block_set - Unknown operation"));
+            }
+            let c_name = utils::new_c_string(name)?;
+            bindings::vips_operation_block_set(c_name.as_ptr(), if state { 1 } else { 0 });
+            Ok(())
+        }
+    }
+
+    /// applies a `BlockPolicy`, replacing the previous one: the operations the previous policy
+    /// denied are unblocked, then the untrusted operations are blocked, then the denied ones, and
+    /// the allowed ones are unblocked last. blocks set with `operation_block_set` are left as they are,
+    /// unless the policies name the same operations
+    pub fn block_policy_set(&self, policy: &BlockPolicy) -> Result<()> {
+        // nothing is changed when a name is unknown
+        for name in policy.denied.iter().chain(&policy.allowed) {
+            if !operation_exists(name)? {
+                return Err(Error::OperationError("VipsApp:block_policy_set - Unknown operation"));
+            }
+        }
+        let mut previous = policy::BLOCK_POLICY.lock().unwrap_or_else(|e| e.into_inner());
+        for name in &previous.denied {
+            self.operation_block_set(name, false)?;
+        }
+        self.block_untrusted_set(policy.untrusted);
+        for name in &policy.denied {
+            self.operation_block_set(name, true)?;
+        }
+        for name in &policy.allowed {
+            self.operation_block_set(name, false)?;
+        }
+        *previous = policy.clone();
+        Ok(())
+    }
+
+    /// checks every image loaded afterwards against the limits of `policy`, before its pixels are
+    /// decoded. `LoadPolicy::new()` removes the limits
+    pub fn load_policy_set(&self, policy: &LoadPolicy) {
+        policy::set_load_policy(policy);
+    }
+}
+
+fn operation_exists(name: &str) -> Result<bool> {
+    let base = utils::new_c_string("VipsOperation")?;
+    let c_name = utils::new_c_string(name)?;
+    unsafe {
+        Ok(bindings::vips_type_find(base.as_ptr(), c_name.as_ptr()) != 0)
+    }
 }
 
 impl Drop for VipsApp {
@@ -228,7 +300,7 @@ fn init(name: &str, detect_leak: bool) -> Result<i32> {
         let result = if res == 0 {
             Ok(res)
         } else {
-            Err(Error::InitializationError("Failed to init libvips"))
+            Err(utils::vips_error(Error::InitializationError("Failed to init libvips")))
         };
         unsafe {
             if detect_leak {
@@ -27,15 +27,22 @@ pub fn linear(inp: &VipsImage, a: &mut[f64], b: &mut[f64]) -> Result<VipsImage>
 }
 
 /// Options for linear operation
+#[derive(Clone, Debug, Default)]
 pub struct LinearOptions {
-    /// uchar: `bool` -> Output should be uchar
-    /// default: false
-    pub uchar: bool,
+    pub uchar: Option<bool>,
 }
 
-impl std::default::Default for LinearOptions {
-    fn default() -> Self {
-        LinearOptions { uchar: false }
+impl LinearOptions {
+    /// options with no argument set, so libvips uses its own defaults
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// uchar: `bool` -> Output should be uchar
+    /// default: false
+    pub fn uchar(mut self, uchar: bool) -> Self {
+        self.uchar = Some(uchar);
+        self
     }
 }
 
@@ -51,34 +58,18 @@ pub fn linear_with_opts(
     b: &mut [f64],
     linear_options: &LinearOptions,
 ) -> Result<VipsImage> {
-    unsafe {
-        if a.len() != b.len() {
-            return Err(Error::LinearError)
-        }
-        let inp_in: *mut bindings::VipsImage = inp.ctx;
-        let a_in: *mut f64 = a.as_mut_ptr();
-        let b_in: *mut f64 = b.as_mut_ptr();
-        let mut out_out: *mut bindings::VipsImage = null_mut();
-
-        let uchar_in: i32 = if linear_options.uchar { 1 } else { 0 };
-        let uchar_in_name = utils::new_c_string("uchar")?;
-
-        let vips_op_response = bindings::vips_linear(
-            inp_in,
-            &mut out_out,
-            a_in,
-            b_in,
-            b.len() as i32,
-            uchar_in_name.as_ptr(),
-            uchar_in,
-            NULL,
-        );
-        utils::result(
-            vips_op_response,
-            || VipsImage { ctx: out_out },
-            Error::LinearError,
-        )
+    if a.len() != b.len() {
+        return Err(Error::LinearError)
+    }
+    let mut operation = utils::VipsOperationWrapper::new("linear", || Error::LinearError)?;
+    operation.set_image("in", inp)?;
+    operation.set_doubles("a", a)?;
+    operation.set_doubles("b", b)?;
+    if let Some(uchar) = linear_options.uchar {
+        operation.set_bool("uchar", uchar)?;
     }
+    operation.build()?;
+    operation.get_image("out")
 }
 
 /// VipsGetpoint (getpoint), read a point from an image
@@ -0,0 +1,983 @@
+// (c) Copyright 2019-2026 OLX
+use crate::bindings;
+use crate::error::Error;
+use crate::utils;
+use crate::utils::{
+    G_TYPE_BOOLEAN, G_TYPE_DOUBLE, G_TYPE_ENUM, G_TYPE_FLAGS, G_TYPE_INT, G_TYPE_OBJECT,
+    G_TYPE_STRING, G_TYPE_UINT64,
+};
+use crate::Result;
+use crate::{VipsImage, VipsInterpolate, VipsSource, VipsTarget};
+
+use std::ffi::{c_void, CStr};
+use std::ptr::null_mut;
+
+/// The value of an argument of an `Operation`, converted to the type libvips declares for the
+/// argument when the operation is called
+#[derive(Debug, Clone)]
+pub enum ArgumentValue {
+    Bool(bool),
+    /// also accepted by enum, flags, double and uint64 arguments
+    Int(i32),
+    UInt64(u64),
+    Double(f64),
+    /// also accepted by enum and flags arguments, as the nickname of the value (e.g. `"srgb"`)
+    String(String),
+    Blob(Vec<u8>),
+    ArrayInt(Vec<i32>),
+    ArrayDouble(Vec<f64>),
+    Image(VipsImage),
+    ArrayImage(Vec<VipsImage>),
+    Interpolate(VipsInterpolate),
+    Source(VipsSource),
+    Target(VipsTarget),
+}
+
+impl ArgumentValue {
+    fn type_name(&self) -> &'static str {
+        match self {
+            ArgumentValue::Bool(_) => "bool",
+            ArgumentValue::Int(_) => "i32",
+            ArgumentValue::UInt64(_) => "u64",
+            ArgumentValue::Double(_) => "f64",
+            ArgumentValue::String(_) => "String",
+            ArgumentValue::Blob(_) => "Vec<u8>",
+            ArgumentValue::ArrayInt(_) => "Vec<i32>",
+            ArgumentValue::ArrayDouble(_) => "Vec<f64>",
+            ArgumentValue::Image(_) => "VipsImage",
+            ArgumentValue::ArrayImage(_) => "Vec<VipsImage>",
+            ArgumentValue::Interpolate(_) => "VipsInterpolate",
+            ArgumentValue::Source(_) => "VipsSource",
+            ArgumentValue::Target(_) => "VipsTarget",
+        }
+    }
+}
+
+impl From<bool> for ArgumentValue {
+    fn from(value: bool) -> Self {
+        ArgumentValue::Bool(value)
+    }
+}
+
+impl From<i32> for ArgumentValue {
+    fn from(value: i32) -> Self {
+        ArgumentValue::Int(value)
+    }
+}
+
+impl From<u64> for ArgumentValue {
+    fn from(value: u64) -> Self {
+        ArgumentValue::UInt64(value)
+    }
+}
+
+impl From<f64> for ArgumentValue {
+    fn from(value: f64) -> Self {
+        ArgumentValue::Double(value)
+    }
+}
+
+impl From<&str> for ArgumentValue {
+    fn from(value: &str) -> Self {
+        ArgumentValue::String(value.to_string())
+    }
+}
+
+impl From<String> for ArgumentValue {
+    fn from(value: String) -> Self {
+        ArgumentValue::String(value)
+    }
+}
+
+impl From<&[u8]> for ArgumentValue {
+    fn from(value: &[u8]) -> Self {
+        ArgumentValue::Blob(value.to_vec())
+    }
+}
+
+impl From<&[i32]> for ArgumentValue {
+    fn from(value: &[i32]) -> Self {
+        ArgumentValue::ArrayInt(value.to_vec())
+    }
+}
+
+impl From<&[f64]> for ArgumentValue {
+    fn from(value: &[f64]) -> Self {
+        ArgumentValue::ArrayDouble(value.to_vec())
+    }
+}
+
+impl From<&VipsImage> for ArgumentValue {
+    fn from(value: &VipsImage) -> Self {
+        ArgumentValue::Image(value.clone())
+    }
+}
+
+impl From<&[VipsImage]> for ArgumentValue {
+    fn from(value: &[VipsImage]) -> Self {
+        ArgumentValue::ArrayImage(value.to_vec())
+    }
+}
+
+impl From<&VipsInterpolate> for ArgumentValue {
+    fn from(value: &VipsInterpolate) -> Self {
+        ArgumentValue::Interpolate(value.clone())
+    }
+}
+
+impl From<&VipsSource> for ArgumentValue {
+    fn from(value: &VipsSource) -> Self {
+        ArgumentValue::Source(value.clone())
+    }
+}
+
+impl From<&VipsTarget> for ArgumentValue {
+    fn from(value: &VipsTarget) -> Self {
+        ArgumentValue::Target(value.clone())
+    }
+}
+
+/// A libvips operation called by its nickname, which reaches the operations without a wrapper in
+/// `ops`, like the ones of plugins or of a newer libvips
+///
+/// The arguments are only checked against the operation when it is called:
+/// `Operation::new("crop").set("in", &image).set("left", 10)...call()?`
+#[derive(Debug, Clone)]
+pub struct Operation {
+    nickname: String,
+    arguments: Vec<(
+        String,
+        ArgumentValue,
+    )>,
+}
+
+impl Operation {
+    pub fn new(nickname: &str) -> Operation {
+        Operation {
+            nickname: nickname.to_string(),
+            arguments: Vec::new(),
+        }
+    }
+
+    /// sets an argument for `call`. images given to MODIFY arguments, the ones drawn on, are not
+    /// changed: `call` draws on a private copy, like the `draw_*` operations of `ops` do
+    pub fn set(mut self, name: &str, value: impl Into<ArgumentValue>) -> Operation {
+        self.arguments
+            .push((
+                name.to_string(),
+                value.into(),
+            ));
+        self
+    }
+
+    /// builds the operation, or takes the matching one from the libvips operation cache, and
+    /// returns its outputs. the copies of the images given to MODIFY arguments are returned among
+    /// the outputs, under the name of the argument, e.g. `outputs.get_image("image")` after `draw_rect`.
+    /// loaders are checked against the `LoadPolicy` like the ones of `ops`
+    pub fn call(&self) -> Result<OperationOutputs> {
+        let mut operation = utils::VipsOperationWrapper::new(
+            &self.nickname,
+            || Error::OperationError("Operation:call - Could not run operation"),
+        )
+        .map_err(|e| {
+            utils::with_operation(
+                e,
+                &self.nickname,
+            )
+        })?;
+        let nickname = utils::new_c_string(&self.nickname)?;
+        let is_load = unsafe {
+            let base = utils::new_c_string("VipsForeignLoad")?;
+            bindings::vips_type_find(
+                base.as_ptr(),
+                nickname.as_ptr(),
+            ) != 0
+        };
+        for (name, value) in &self.arguments {
+            if is_load {
+                check_load_input(
+                    name, value,
+                )?;
+            }
+            unsafe {
+                let name_c_str = utils::new_c_string(name)?;
+                let (gtype, flags) = argument_type(
+                    operation.object(),
+                    name,
+                    &name_c_str,
+                )
+                .map_err(|e| {
+                    utils::with_operation(
+                        e,
+                        &self.nickname,
+                    )
+                })?;
+                // images modified in place are made private first, so the caller's image is left as
+                // it is. the copy is rendered to memory only once, when the operation draws on it
+                let copy;
+                let value = match value {
+                    ArgumentValue::Image(image)
+                        if flags & bindings::VipsArgumentFlags_VIPS_ARGUMENT_MODIFY != 0 =>
+                    {
+                        let mut image = image.clone();
+                        image.make_private()?;
+                        copy = ArgumentValue::Image(image);
+                        &copy
+                    }
+                    value => value,
+                };
+                let gvalue = to_gvalue(
+                    value,
+                    name,
+                    gtype,
+                    &nickname,
+                )
+                .map_err(|e| {
+                    utils::with_operation(
+                        e,
+                        &self.nickname,
+                    )
+                })?;
+                bindings::g_object_set_property(
+                    operation.object() as *mut bindings::GObject,
+                    name_c_str.as_ptr(),
+                    &gvalue.inner,
+                );
+            }
+        }
+        operation
+            .build()
+            .map_err(|e| {
+                let e = match self.source() {
+                    Some(source) if is_load => utils::with_source_limit(e, source),
+                    _ => e,
+                };
+                utils::with_operation(
+                    e,
+                    &self.nickname,
+                )
+            })?;
+        let outputs = OperationOutputs {
+            operation,
+        };
+        if is_load {
+            utils::check_load(&outputs.get_image("out")?)?;
+        }
+        Ok(outputs)
+    }
+
+    fn source(&self) -> Option<&VipsSource> {
+        self.arguments
+            .iter()
+            .find_map(
+                |(name, value)| match value {
+                    ArgumentValue::Source(source) if name == "source" => Some(source),
+                    _ => None,
+                },
+            )
+    }
+}
+
+// the input of a loader checked against the byte limit of the load policy before it is read
+fn check_load_input(name: &str, value: &ArgumentValue) -> Result<()> {
+    match (
+        name, value,
+    ) {
+        ("filename", ArgumentValue::String(filename)) => utils::check_load_file_bytes(filename),
+        ("buffer", ArgumentValue::Blob(buffer)) => utils::check_load_bytes(buffer.len()),
+        ("source", ArgumentValue::Source(source)) => utils::check_load_source_bytes(source),
+        _ => Ok(()),
+    }
+}
+
+/// The outputs of a called `Operation`. The typed getters fail with `Error::ArgumentTypeError`
+/// when the output is of another type
+pub struct OperationOutputs {
+    operation: utils::VipsOperationWrapper,
+}
+
+impl OperationOutputs {
+    /// enums and flags are returned as `ArgumentValue::Int`
+    pub fn get(&self, name: &str) -> Result<ArgumentValue> {
+        unsafe {
+            let name_c_str = utils::new_c_string(name)?;
+            let (gtype, _) = argument_type(
+                self.operation
+                    .object(),
+                name,
+                &name_c_str,
+            )?;
+            let mut gvalue = GValue::new(gtype);
+            bindings::g_object_get_property(
+                self.operation
+                    .object() as *mut bindings::GObject,
+                name_c_str.as_ptr(),
+                &mut gvalue.inner,
+            );
+            from_gvalue(
+                &gvalue,
+                name,
+                gtype,
+            )
+        }
+    }
+
+    pub fn get_bool(&self, name: &str) -> Result<bool> {
+        match self.get(name)? {
+            ArgumentValue::Bool(value) => Ok(value),
+            value => Err(
+                type_error(
+                    name,
+                    "bool",
+                    &value,
+                ),
+            ),
+        }
+    }
+
+    pub fn get_int(&self, name: &str) -> Result<i32> {
+        match self.get(name)? {
+            ArgumentValue::Int(value) => Ok(value),
+            value => Err(
+                type_error(
+                    name,
+                    "i32",
+                    &value,
+                ),
+            ),
+        }
+    }
+
+    pub fn get_uint64(&self, name: &str) -> Result<u64> {
+        match self.get(name)? {
+            ArgumentValue::UInt64(value) => Ok(value),
+            value => Err(
+                type_error(
+                    name,
+                    "u64",
+                    &value,
+                ),
+            ),
+        }
+    }
+
+    pub fn get_double(&self, name: &str) -> Result<f64> {
+        match self.get(name)? {
+            ArgumentValue::Double(value) => Ok(value),
+            value => Err(
+                type_error(
+                    name,
+                    "f64",
+                    &value,
+                ),
+            ),
+        }
+    }
+
+    pub fn get_string(&self, name: &str) -> Result<String> {
+        match self.get(name)? {
+            ArgumentValue::String(value) => Ok(value),
+            value => Err(
+                type_error(
+                    name,
+                    "String",
+                    &value,
+                ),
+            ),
+        }
+    }
+
+    pub fn get_blob(&self, name: &str) -> Result<Vec<u8>> {
+        match self.get(name)? {
+            ArgumentValue::Blob(value) => Ok(value),
+            value => Err(
+                type_error(
+                    name,
+                    "Vec<u8>",
+                    &value,
+                ),
+            ),
+        }
+    }
+
+    pub fn get_array_int(&self, name: &str) -> Result<Vec<i32>> {
+        match self.get(name)? {
+            ArgumentValue::ArrayInt(value) => Ok(value),
+            value => Err(
+                type_error(
+                    name,
+                    "Vec<i32>",
+                    &value,
+                ),
+            ),
+        }
+    }
+
+    pub fn get_array_double(&self, name: &str) -> Result<Vec<f64>> {
+        match self.get(name)? {
+            ArgumentValue::ArrayDouble(value) => Ok(value),
+            value => Err(
+                type_error(
+                    name,
+                    "Vec<f64>",
+                    &value,
+                ),
+            ),
+        }
+    }
+
+    pub fn get_image(&self, name: &str) -> Result<VipsImage> {
+        match self.get(name)? {
+            ArgumentValue::Image(value) => Ok(value),
+            value => Err(
+                type_error(
+                    name,
+                    "VipsImage",
+                    &value,
+                ),
+            ),
+        }
+    }
+
+    pub fn get_array_image(&self, name: &str) -> Result<Vec<VipsImage>> {
+        match self.get(name)? {
+            ArgumentValue::ArrayImage(value) => Ok(value),
+            value => Err(
+                type_error(
+                    name,
+                    "Vec<VipsImage>",
+                    &value,
+                ),
+            ),
+        }
+    }
+}
+
+fn type_error(name: &str, expected: &'static str, value: &ArgumentValue) -> Error {
+    Error::ArgumentTypeError {
+        name: name.to_string(),
+        expected,
+        found: value
+            .type_name()
+            .to_string(),
+    }
+}
+
+// a GValue initialised to a type, unset when dropped
+struct GValue {
+    inner: bindings::GValue,
+}
+
+impl GValue {
+    unsafe fn new(gtype: bindings::GType) -> GValue {
+        let mut inner: bindings::GValue = std::mem::zeroed();
+        bindings::g_value_init(
+            &mut inner,
+            gtype,
+        );
+        GValue {
+            inner,
+        }
+    }
+}
+
+impl Drop for GValue {
+    fn drop(&mut self) {
+        unsafe {
+            bindings::g_value_unset(&mut self.inner);
+        }
+    }
+}
+
+// the type and the flags libvips declares for an argument of the operation
+unsafe fn argument_type(
+    object: *mut bindings::VipsObject,
+    name: &str,
+    name_c_str: &CStr,
+) -> Result<(
+    bindings::GType,
+    bindings::VipsArgumentFlags,
+)> {
+    let mut pspec: *mut bindings::GParamSpec = null_mut();
+    let mut argument_class: *mut bindings::VipsArgumentClass = null_mut();
+    let mut argument_instance: *mut bindings::VipsArgumentInstance = null_mut();
+    let res = bindings::vips_object_get_argument(
+        object,
+        name_c_str.as_ptr(),
+        &mut pspec,
+        &mut argument_class,
+        &mut argument_instance,
+    );
+    if res != 0 || pspec.is_null() || argument_class.is_null() {
+        Err(utils::vips_error(Error::MissingArgumentError(name.to_string())))
+    } else {
+        Ok((
+            (*pspec).value_type,
+            (*argument_class).flags,
+        ))
+    }
+}
+
+fn gtype_name(gtype: bindings::GType) -> String {
+    unsafe {
+        CStr::from_ptr(bindings::g_type_name(gtype))
+            .to_string_lossy()
+            .into_owned()
+    }
+}
+
+unsafe fn to_gvalue(
+    value: &ArgumentValue,
+    name: &str,
+    gtype: bindings::GType,
+    nickname: &CStr,
+) -> Result<GValue> {
+    let mut gvalue = GValue::new(gtype);
+    let ptr = &mut gvalue.inner as *mut bindings::GValue;
+    let fundamental = bindings::g_type_fundamental(gtype);
+    let converted = if gtype == bindings::vips_ref_string_get_type() {
+        match value {
+            ArgumentValue::String(value) => {
+                let value = utils::new_c_string(value)?;
+                bindings::vips_value_set_ref_string(
+                    ptr,
+                    value.as_ptr(),
+                );
+                true
+            }
+            _ => false,
+        }
+    } else if gtype == bindings::vips_blob_get_type() {
+        match value {
+            ArgumentValue::Blob(value) => {
+                let blob = bindings::vips_blob_copy(
+                    value.as_ptr() as *const c_void,
+                    value.len() as u64,
+                );
+                bindings::g_value_set_boxed(
+                    ptr,
+                    blob as *const c_void,
+                );
+                bindings::vips_area_unref(blob as *mut bindings::VipsArea);
+                true
+            }
+            _ => false,
+        }
+    } else if gtype == bindings::vips_array_int_get_type() {
+        let array = match value {
+            ArgumentValue::ArrayInt(value) => Some(utils::VipsArrayIntWrapper::from(&value[..])),
+            ArgumentValue::Int(value) => Some(utils::VipsArrayIntWrapper::from(&[*value][..])),
+            _ => None,
+        };
+        array
+            .map(|array| {
+                bindings::g_value_set_boxed(
+                    ptr,
+                    array.ctx as *const c_void,
+                )
+            })
+            .is_some()
+    } else if gtype == bindings::vips_array_double_get_type() {
+        let values = match value {
+            ArgumentValue::ArrayDouble(value) => Some(value.clone()),
+            ArgumentValue::ArrayInt(value) => Some(
+                value
+                    .iter()
+                    .map(|v| *v as f64)
+                    .collect(),
+            ),
+            ArgumentValue::Double(value) => Some(vec![
+                *value,
+            ]),
+            ArgumentValue::Int(value) => Some(vec![
+                *value as f64,
+            ]),
+            _ => None,
+        };
+        values
+            .map(|values| {
+                let array = utils::VipsArrayDoubleWrapper::from(&values[..]);
+                bindings::g_value_set_boxed(
+                    ptr,
+                    array.ctx as *const c_void,
+                )
+            })
+            .is_some()
+    } else if gtype == bindings::vips_array_image_get_type() {
+        let array = match value {
+            ArgumentValue::ArrayImage(value) => {
+                Some(utils::VipsArrayImageWrapper::from(&value[..]))
+            }
+            ArgumentValue::Image(value) => {
+                Some(utils::VipsArrayImageWrapper::from(std::slice::from_ref(value)))
+            }
+            _ => None,
+        };
+        array
+            .map(|array| {
+                bindings::g_value_set_boxed(
+                    ptr,
+                    array.ctx as *const c_void,
+                )
+            })
+            .is_some()
+    } else {
+        match (
+            fundamental,
+            value,
+        ) {
+            (G_TYPE_BOOLEAN, ArgumentValue::Bool(value)) => {
+                bindings::g_value_set_boolean(
+                    ptr,
+                    if *value { 1 } else { 0 },
+                );
+                true
+            }
+            (G_TYPE_INT, ArgumentValue::Int(value)) => {
+                bindings::g_value_set_int(
+                    ptr,
+                    *value,
+                );
+                true
+            }
+            (G_TYPE_UINT64, ArgumentValue::UInt64(value)) => {
+                bindings::g_value_set_uint64(
+                    ptr,
+                    *value,
+                );
+                true
+            }
+            (G_TYPE_UINT64, ArgumentValue::Int(value)) if *value >= 0 => {
+                bindings::g_value_set_uint64(
+                    ptr,
+                    *value as u64,
+                );
+                true
+            }
+            (G_TYPE_DOUBLE, ArgumentValue::Double(value)) => {
+                bindings::g_value_set_double(
+                    ptr,
+                    *value,
+                );
+                true
+            }
+            (G_TYPE_DOUBLE, ArgumentValue::Int(value)) => {
+                bindings::g_value_set_double(
+                    ptr,
+                    *value as f64,
+                );
+                true
+            }
+            (G_TYPE_ENUM, ArgumentValue::Int(value)) => {
+                bindings::g_value_set_enum(
+                    ptr,
+                    *value,
+                );
+                true
+            }
+            (G_TYPE_ENUM, ArgumentValue::String(value)) => {
+                let value_c_str = utils::new_c_string(value)?;
+                let value = bindings::vips_enum_from_nick(
+                    nickname.as_ptr(),
+                    gtype,
+                    value_c_str.as_ptr(),
+                );
+                if value < 0 {
+                    return Err(
+                        utils::vips_error(
+                            Error::OperationError("Operation:call - Unknown enum value"),
+                        ),
+                    );
+                }
+                bindings::g_value_set_enum(ptr, value);
+                true
+            }
+            (G_TYPE_FLAGS, ArgumentValue::Int(value)) => {
+                bindings::g_value_set_flags(
+                    ptr,
+                    *value as u32,
+                );
+                true
+            }
+            (G_TYPE_FLAGS, ArgumentValue::String(value)) => {
+                let value_c_str = utils::new_c_string(value)?;
+                let value = bindings::vips_flags_from_nick(
+                    nickname.as_ptr(),
+                    gtype,
+                    value_c_str.as_ptr(),
+                );
+                if value < 0 {
+                    return Err(
+                        utils::vips_error(
+                            Error::OperationError("Operation:call - Unknown flags value"),
+                        ),
+                    );
+                }
+                bindings::g_value_set_flags(
+                    ptr,
+                    value as u32,
+                );
+                true
+            }
+            (G_TYPE_STRING, ArgumentValue::String(value)) => {
+                let value = utils::new_c_string(value)?;
+                bindings::g_value_set_string(
+                    ptr,
+                    value.as_ptr(),
+                );
+                true
+            }
+            (_, ArgumentValue::Image(value))
+                if bindings::g_type_is_a(
+                    gtype,
+                    bindings::vips_image_get_type(),
+                ) != 0 =>
+            {
+                bindings::g_value_set_object(
+                    ptr,
+                    value.ctx as bindings::gpointer,
+                );
+                true
+            }
+            (_, ArgumentValue::Interpolate(value))
+                if bindings::g_type_is_a(
+                    gtype,
+                    bindings::vips_interpolate_get_type(),
+                ) != 0 =>
+            {
+                bindings::g_value_set_object(
+                    ptr,
+                    value.ctx as bindings::gpointer,
+                );
+                true
+            }
+            (_, ArgumentValue::Source(value))
+                if bindings::g_type_is_a(
+                    gtype,
+                    bindings::vips_source_get_type(),
+                ) != 0 =>
+            {
+                bindings::g_value_set_object(
+                    ptr,
+                    value.ctx as bindings::gpointer,
+                );
+                true
+            }
+            (_, ArgumentValue::Target(value))
+                if bindings::g_type_is_a(
+                    gtype,
+                    bindings::vips_target_get_type(),
+                ) != 0 =>
+            {
+                bindings::g_value_set_object(
+                    ptr,
+                    value.ctx as bindings::gpointer,
+                );
+                true
+            }
+            _ => false,
+        }
+    };
+    if converted {
+        Ok(gvalue)
+    } else {
+        Err(
+            Error::ArgumentTypeError {
+                name: name.to_string(),
+                expected: value.type_name(),
+                found: gtype_name(gtype),
+            },
+        )
+    }
+}
+
+// objects and boxed values are referenced or copied, as the GValue releases its own ones
+unsafe fn from_gvalue(
+    gvalue: &GValue,
+    name: &str,
+    gtype: bindings::GType,
+) -> Result<ArgumentValue> {
+    let ptr = &gvalue.inner as *const bindings::GValue;
+    let missing = || Error::MissingArgumentError(name.to_string());
+    let unsupported = || Error::ArgumentTypeError {
+        name: name.to_string(),
+        expected: "ArgumentValue",
+        found: gtype_name(gtype),
+    };
+    let fundamental = bindings::g_type_fundamental(gtype);
+    if gtype == bindings::vips_ref_string_get_type() {
+        let mut length: u64 = 0;
+        let value = bindings::vips_value_get_ref_string(
+            ptr,
+            &mut length,
+        );
+        if value.is_null() {
+            return Err(missing());
+        }
+        Ok(
+            ArgumentValue::String(
+                CStr::from_ptr(value)
+                    .to_string_lossy()
+                    .into_owned(),
+            ),
+        )
+    } else if gtype == bindings::vips_blob_get_type() {
+        let mut length: u64 = 0;
+        let value = bindings::vips_value_get_blob(
+            ptr,
+            &mut length,
+        );
+        if value.is_null() {
+            return Err(missing());
+        }
+        Ok(
+            ArgumentValue::Blob(
+                std::slice::from_raw_parts(
+                    value as *const u8,
+                    length as usize,
+                )
+                .to_vec(),
+            ),
+        )
+    } else if gtype == bindings::vips_array_int_get_type() {
+        let mut length: i32 = 0;
+        let value = bindings::vips_value_get_array_int(
+            ptr,
+            &mut length,
+        );
+        if value.is_null() {
+            return Ok(ArgumentValue::ArrayInt(Vec::new()));
+        }
+        Ok(
+            ArgumentValue::ArrayInt(
+                utils::new_int_array(
+                    value,
+                    length as u64,
+                ),
+            ),
+        )
+    } else if gtype == bindings::vips_array_double_get_type() {
+        let mut length: i32 = 0;
+        let value = bindings::vips_value_get_array_double(
+            ptr,
+            &mut length,
+        );
+        if value.is_null() {
+            return Ok(ArgumentValue::ArrayDouble(Vec::new()));
+        }
+        Ok(
+            ArgumentValue::ArrayDouble(
+                utils::new_double_array(
+                    value,
+                    length as u64,
+                ),
+            ),
+        )
+    } else if gtype == bindings::vips_array_image_get_type() {
+        let mut length: i32 = 0;
+        let value = bindings::vips_value_get_array_image(
+            ptr,
+            &mut length,
+        );
+        if value.is_null() {
+            return Ok(ArgumentValue::ArrayImage(Vec::new()));
+        }
+        let images = std::slice::from_raw_parts(
+            value,
+            length as usize,
+        )
+        .iter()
+        .map(|image| {
+            bindings::g_object_ref(*image as bindings::gpointer);
+            VipsImage {
+                ctx: *image,
+            }
+        })
+        .collect();
+        Ok(ArgumentValue::ArrayImage(images))
+    } else {
+        match fundamental {
+            G_TYPE_BOOLEAN => Ok(ArgumentValue::Bool(bindings::g_value_get_boolean(ptr) != 0)),
+            G_TYPE_INT => Ok(ArgumentValue::Int(bindings::g_value_get_int(ptr))),
+            G_TYPE_UINT64 => Ok(ArgumentValue::UInt64(bindings::g_value_get_uint64(ptr))),
+            G_TYPE_DOUBLE => Ok(ArgumentValue::Double(bindings::g_value_get_double(ptr))),
+            G_TYPE_ENUM => Ok(ArgumentValue::Int(bindings::g_value_get_enum(ptr))),
+            G_TYPE_FLAGS => Ok(ArgumentValue::Int(bindings::g_value_get_flags(ptr) as i32)),
+            G_TYPE_STRING => {
+                let value = bindings::g_value_get_string(ptr);
+                if value.is_null() {
+                    return Err(missing());
+                }
+                Ok(
+                    ArgumentValue::String(
+                        CStr::from_ptr(value)
+                            .to_string_lossy()
+                            .into_owned(),
+                    ),
+                )
+            }
+            G_TYPE_OBJECT => {
+                let object = bindings::g_value_get_object(ptr);
+                if object.is_null() {
+                    return Err(missing());
+                }
+                if bindings::g_type_is_a(
+                    gtype,
+                    bindings::vips_image_get_type(),
+                ) != 0
+                {
+                    bindings::g_object_ref(object);
+                    Ok(
+                        ArgumentValue::Image(
+                            VipsImage {
+                                ctx: object as *mut bindings::VipsImage,
+                            },
+                        ),
+                    )
+                } else if bindings::g_type_is_a(
+                    gtype,
+                    bindings::vips_interpolate_get_type(),
+                ) != 0
+                {
+                    bindings::g_object_ref(object);
+                    Ok(
+                        ArgumentValue::Interpolate(
+                            VipsInterpolate {
+                                ctx: object as *mut bindings::VipsInterpolate,
+                            },
+                        ),
+                    )
+                } else if bindings::g_type_is_a(
+                    gtype,
+                    bindings::vips_source_get_type(),
+                ) != 0
+                {
+                    bindings::g_object_ref(object);
+                    Ok(
+                        ArgumentValue::Source(
+                            VipsSource {
+                                ctx: object as *mut bindings::VipsSource,
+                            },
+                        ),
+                    )
+                } else if bindings::g_type_is_a(
+                    gtype,
+                    bindings::vips_target_get_type(),
+                ) != 0
+                {
+                    bindings::g_object_ref(object);
+                    Ok(
+                        ArgumentValue::Target(
+                            VipsTarget {
+                                ctx: object as *mut bindings::VipsTarget,
+                            },
+                        ),
+                    )
+                } else {
+                    Err(unsupported())
+                }
+            }
+            _ => Err(unsupported()),
+        }
+    }
+}
@@ -0,0 +1,184 @@
+// (c) Copyright 2019-2026 OLX
+use crate::error::Error;
+use crate::Result;
+use crate::VipsImage;
+
+use std::sync::{Mutex, RwLock};
+
+/// Which operations libvips may run, set with `VipsApp::block_policy_set`. Blocking an operation
+/// also blocks the ones derived from it, so a whole family can be denied (e.g. `VipsForeignLoad`)
+/// and some of its members allowed back (e.g. `jpegload_source`). A policy replaces the one set
+/// before it: the operations the previous policy blocked are unblocked first
+#[derive(Clone, Debug, Default)]
+pub struct BlockPolicy {
+    pub(crate) untrusted: bool,
+    pub(crate) denied: Vec<String>,
+    pub(crate) allowed: Vec<String>,
+}
+
+impl BlockPolicy {
+    /// a policy blocking nothing
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// blocks the operations libvips flags as untrusted
+    pub fn block_untrusted(mut self, state: bool) -> Self {
+        self.untrusted = state;
+        self
+    }
+
+    /// blocks an operation, given by nickname or type name
+    pub fn deny(mut self, name: &str) -> Self {
+        self.denied
+            .push(name.to_string());
+        self
+    }
+
+    /// unblocks an operation blocked as untrusted or by a denied one it derives from
+    pub fn allow(mut self, name: &str) -> Self {
+        self.allowed
+            .push(name.to_string());
+        self
+    }
+}
+
+/// Limits on the images the loaders may open, against decompression bombs. libvips reads the
+/// header when an image is loaded and decodes the pixels only when they are needed, so the limits
+/// are checked before any decode. Set it with `VipsApp::load_policy_set` to check every load, or
+/// call `check` on an image loaded with other options
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LoadPolicy {
+    max_width: Option<i32>,
+    max_height: Option<i32>,
+    max_pixels: Option<u64>,
+    max_pages: Option<i32>,
+    max_bytes: Option<u64>,
+}
+
+impl LoadPolicy {
+    /// a policy without limits
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn max_width(mut self, max_width: i32) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// for multi page loads the height of all the loaded pages
+    pub fn max_height(mut self, max_height: i32) -> Self {
+        self.max_height = Some(max_height);
+        self
+    }
+
+    /// width times height
+    pub fn max_pixels(mut self, max_pixels: u64) -> Self {
+        self.max_pixels = Some(max_pixels);
+        self
+    }
+
+    /// the number of pages in the file, whatever the number of pages loaded
+    pub fn max_pages(mut self, max_pages: i32) -> Self {
+        self.max_pages = Some(max_pages);
+        self
+    }
+
+    /// the size of the buffers, files and seekable sources given to the loaders. the sources created
+    /// with `VipsSource::from_unseekable_reader` fail once they are read past it
+    pub fn max_bytes(mut self, max_bytes: u64) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+
+    /// fails with `Error::LimitExceeded` for the first limit the header of the image exceeds
+    pub fn check(&self, image: &VipsImage) -> Result<()> {
+        let width = image.get_width();
+        let height = image.get_height();
+        exceeds(
+            "width",
+            width as u64,
+            self.max_width
+                .map(|max| max as u64),
+        )?;
+        exceeds(
+            "height",
+            height as u64,
+            self.max_height
+                .map(|max| max as u64),
+        )?;
+        exceeds(
+            "pixels",
+            width as u64 * height as u64,
+            self.max_pixels,
+        )?;
+        exceeds(
+            "pages",
+            image.get_n_pages() as u64,
+            self.max_pages
+                .map(|max| max as u64),
+        )
+    }
+
+    pub(crate) fn check_bytes(&self, bytes: u64) -> Result<()> {
+        exceeds(
+            "bytes",
+            bytes,
+            self.max_bytes,
+        )
+    }
+
+    pub(crate) fn limits_bytes(&self) -> bool {
+        self.max_bytes
+            .is_some()
+    }
+
+    pub(crate) fn is_unlimited(&self) -> bool {
+        *self == LoadPolicy::default()
+    }
+}
+
+fn exceeds(limit: &'static str, value: u64, max: Option<u64>) -> Result<()> {
+    match max {
+        Some(max) if value > max => Err(
+            Error::LimitExceeded {
+                limit,
+                value,
+                max,
+            },
+        ),
+        _ => Ok(()),
+    }
+}
+
+static LOAD_POLICY: RwLock<LoadPolicy> = RwLock::new(
+    LoadPolicy {
+        max_width: None,
+        max_height: None,
+        max_pixels: None,
+        max_pages: None,
+        max_bytes: None,
+    },
+);
+
+pub(crate) fn load_policy() -> LoadPolicy {
+    *LOAD_POLICY
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+}
+
+pub(crate) fn set_load_policy(policy: &LoadPolicy) {
+    *LOAD_POLICY
+        .write()
+        .unwrap_or_else(|e| e.into_inner()) = *policy;
+}
+
+// the block policy applied last, whose blocks are undone when the next one is applied
+pub(crate) static BLOCK_POLICY: Mutex<BlockPolicy> = Mutex::new(
+    BlockPolicy {
+        untrusted: false,
+        denied: Vec::new(),
+        allowed: Vec::new(),
+    },
+);
@@ -0,0 +1,306 @@
+// (c) Copyright 2019-2026 OLX
+use crate::bindings;
+use crate::utils;
+use crate::Result;
+use crate::VipsImage;
+
+use std::ffi::c_void;
+use std::os::raw::c_char;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr::null_mut;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Snapshot of the evaluation of an image, passed to progress callbacks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VipsProgress {
+    /// seconds since the evaluation started
+    pub run: i32,
+    /// estimated seconds until the evaluation ends
+    pub eta: i32,
+    /// number of pixels of the whole image
+    pub total_pixels: i64,
+    /// number of pixels computed so far
+    pub processed_pixels: i64,
+    /// percentage of the image computed so far
+    pub percent: i32,
+}
+
+/// Signal a progress callback is called for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvalStage {
+    /// `preeval`, the evaluation is about to start
+    Start,
+    /// `eval`, a part of the image has been computed
+    Progress,
+    /// `posteval`, the evaluation has finished
+    End,
+}
+
+/// What the evaluation should do after a progress callback returns
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvalAction {
+    Continue,
+    /// kills the evaluation, which then fails with `Error::Cancelled`
+    Cancel,
+}
+
+type ProgressCallback = Box<dyn FnMut(EvalStage, &VipsProgress) -> EvalAction + Send>;
+
+type ProgressFn =
+    unsafe extern "C" fn(*mut bindings::VipsImage, *mut bindings::VipsProgress, *mut c_void);
+
+// the callback of an image, shared by its three signal handlers and the image itself, which keeps
+// it to replace it and to report the cancellation
+struct ProgressState {
+    callback: Mutex<ProgressCallback>,
+    // set when the callback cancelled the current evaluation
+    cancelled: AtomicBool,
+    handlers: Mutex<Vec<bindings::gulong>>,
+}
+
+// key of the state in the data of the image
+const PROGRESS_KEY: &[u8] = b"libvips-rust-progress\0";
+
+// serializes replacing the callback of an image with reading its state
+static PROGRESS_LOCK: Mutex<()> = Mutex::new(());
+
+impl VipsImage {
+    /// calls `callback` when the evaluation of this image starts, every time a part of it is computed
+    /// and when it ends. this should be the image being saved or copied to memory, as the signals are not sent
+    /// for the images earlier in the pipeline. callbacks can be called from the libvips worker threads.
+    /// returning `EvalAction::Cancel` stops the evaluation, for example once `run` exceeds a timeout.
+    /// the callback replaces the one set before, if any
+    pub fn set_progress_callback<F>(&mut self, callback: F) -> Result<()>
+    where
+        F: FnMut(EvalStage, &VipsProgress) -> EvalAction + Send + 'static,
+    {
+        let preeval_signal = utils::new_c_string("preeval")?;
+        let eval_signal = utils::new_c_string("eval")?;
+        let posteval_signal = utils::new_c_string("posteval")?;
+        // the callback belongs to this handle, not to the clones and cached images sharing the image
+        self.make_private()?;
+        let _lock = PROGRESS_LOCK
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        unsafe {
+            self.disconnect_progress();
+            bindings::vips_image_set_progress(
+                self.ctx,
+                1,
+            );
+            let callback: ProgressCallback = Box::new(callback);
+            let state = Arc::new(
+                ProgressState {
+                    callback: Mutex::new(callback),
+                    cancelled: AtomicBool::new(false),
+                    handlers: Mutex::new(Vec::new()),
+                },
+            );
+            // every handler holds a reference to the state, released when it is disconnected.
+            // GLib keeps a running handler alive until it returns
+            let handlers = [
+                (
+                    &preeval_signal,
+                    on_preeval as ProgressFn,
+                ),
+                (
+                    &eval_signal,
+                    on_eval as ProgressFn,
+                ),
+                (
+                    &posteval_signal,
+                    on_posteval as ProgressFn,
+                ),
+            ]
+            .into_iter()
+            .map(
+                |(signal, handler)| {
+                    utils::signal_connect(
+                        self.ctx as *mut c_void,
+                        signal,
+                        Some(std::mem::transmute::<ProgressFn, unsafe extern "C" fn()>(handler)),
+                        Arc::into_raw(state.clone()) as *mut c_void,
+                        Some(release_handler_state),
+                    )
+                },
+            )
+            .collect();
+            *state
+                .handlers
+                .lock()
+                .unwrap_or_else(|e| e.into_inner()) = handlers;
+            bindings::g_object_set_data_full(
+                self.ctx as *mut bindings::GObject,
+                PROGRESS_KEY.as_ptr() as *const c_char,
+                Arc::into_raw(state) as *mut c_void,
+                Some(release_image_state),
+            );
+        }
+        Ok(())
+    }
+
+    /// removes the callback set with `set_progress_callback`
+    pub fn clear_progress_callback(&mut self) -> Result<()> {
+        self.make_private()?;
+        let _lock = PROGRESS_LOCK
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        unsafe {
+            self.disconnect_progress();
+            bindings::vips_image_set_progress(
+                self.ctx,
+                0,
+            );
+        }
+        Ok(())
+    }
+
+    // disconnects the handlers of the current callback and releases the state kept by the image
+    unsafe fn disconnect_progress(&self) {
+        let state = progress_state(self);
+        if state.is_null() {
+            return;
+        }
+        let handlers = (*state)
+            .handlers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone();
+        for handler in handlers {
+            bindings::g_signal_handler_disconnect(
+                self.ctx as bindings::gpointer,
+                handler,
+            );
+        }
+        bindings::g_object_set_data(
+            self.ctx as *mut bindings::GObject,
+            PROGRESS_KEY.as_ptr() as *const c_char,
+            null_mut(),
+        );
+    }
+}
+
+unsafe fn progress_state(image: &VipsImage) -> *const ProgressState {
+    bindings::g_object_get_data(
+        image.ctx as *mut bindings::GObject,
+        PROGRESS_KEY.as_ptr() as *const c_char,
+    ) as *const ProgressState
+}
+
+/// Whether the progress callback of the image cancelled its last evaluation, which makes the
+/// call evaluating it fail.
+pub(crate) fn cancelled(image: &VipsImage) -> bool {
+    let _lock = PROGRESS_LOCK
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    unsafe {
+        let state = progress_state(image);
+        !state.is_null()
+            && (*state)
+                .cancelled
+                .load(Ordering::SeqCst)
+    }
+}
+
+unsafe extern "C" fn on_preeval(
+    image: *mut bindings::VipsImage,
+    progress: *mut bindings::VipsProgress,
+    state: *mut c_void,
+) {
+    call_progress_callback(
+        EvalStage::Start,
+        image,
+        progress,
+        state,
+    );
+}
+
+unsafe extern "C" fn on_eval(
+    image: *mut bindings::VipsImage,
+    progress: *mut bindings::VipsProgress,
+    state: *mut c_void,
+) {
+    call_progress_callback(
+        EvalStage::Progress,
+        image,
+        progress,
+        state,
+    );
+}
+
+unsafe extern "C" fn on_posteval(
+    image: *mut bindings::VipsImage,
+    progress: *mut bindings::VipsProgress,
+    state: *mut c_void,
+) {
+    call_progress_callback(
+        EvalStage::End,
+        image,
+        progress,
+        state,
+    );
+}
+
+// a panicking callback must not unwind into C, so it cancels the evaluation instead
+unsafe fn call_progress_callback(
+    stage: EvalStage,
+    image: *mut bindings::VipsImage,
+    progress: *mut bindings::VipsProgress,
+    state: *mut c_void,
+) {
+    let state = &*(state as *const ProgressState);
+    if stage == EvalStage::Start {
+        state
+            .cancelled
+            .store(
+                false,
+                Ordering::SeqCst,
+            );
+    }
+    if progress.is_null() {
+        return;
+    }
+    let progress = &*progress;
+    let snapshot = VipsProgress {
+        run: progress.run,
+        eta: progress.eta,
+        total_pixels: progress.tpels,
+        processed_pixels: progress.npels,
+        percent: progress.percent,
+    };
+    let action = panic::catch_unwind(
+        AssertUnwindSafe(|| {
+            match state
+                .callback
+                .lock()
+            {
+                Ok(mut callback) => callback(
+                    stage,
+                    &snapshot,
+                ),
+                Err(_) => EvalAction::Cancel,
+            }
+        }),
+    );
+    if !matches!(
+        action,
+        Ok(EvalAction::Continue)
+    ) {
+        state
+            .cancelled
+            .store(
+                true,
+                Ordering::SeqCst,
+            );
+        bindings::vips_image_set_kill(image, 1);
+    }
+}
+
+unsafe extern "C" fn release_handler_state(state: *mut c_void, _: *mut bindings::GClosure) {
+    drop(Arc::from_raw(state as *const ProgressState));
+}
+
+unsafe extern "C" fn release_image_state(state: *mut c_void) {
+    drop(Arc::from_raw(state as *const ProgressState));
+}
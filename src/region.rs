@@ -0,0 +1,288 @@
+// (c) Copyright 2019-2026 OLX
+use crate::bindings;
+use crate::error::Error;
+use crate::ops::BandFormat;
+use crate::utils;
+use crate::Result;
+use crate::VipsImage;
+
+use num_traits::FromPrimitive;
+use std::ffi::c_void;
+
+/// A rectangle of pixels from an image. Only the pixels of the prepared area are computed, so
+/// small windows of huge images can be read without rendering the whole image to memory.
+#[derive(Debug)]
+pub struct VipsRegion {
+    pub(crate) ctx: *mut bindings::VipsRegion,
+}
+
+/// Rust type of a single band value for each `BandFormat`, used to read typed pixels from a `VipsRegion`.
+/// Complex formats are read as `[real, imaginary]` pairs.
+pub trait BandFormatType: Copy {
+    const FORMAT: BandFormat;
+}
+
+impl BandFormatType for u8 {
+    const FORMAT: BandFormat = BandFormat::Uchar;
+}
+
+impl BandFormatType for i8 {
+    const FORMAT: BandFormat = BandFormat::Char;
+}
+
+impl BandFormatType for u16 {
+    const FORMAT: BandFormat = BandFormat::Ushort;
+}
+
+impl BandFormatType for i16 {
+    const FORMAT: BandFormat = BandFormat::Short;
+}
+
+impl BandFormatType for u32 {
+    const FORMAT: BandFormat = BandFormat::Uint;
+}
+
+impl BandFormatType for i32 {
+    const FORMAT: BandFormat = BandFormat::Int;
+}
+
+impl BandFormatType for f32 {
+    const FORMAT: BandFormat = BandFormat::Float;
+}
+
+impl BandFormatType for [f32; 2] {
+    const FORMAT: BandFormat = BandFormat::Complex;
+}
+
+impl BandFormatType for f64 {
+    const FORMAT: BandFormat = BandFormat::Double;
+}
+
+impl BandFormatType for [f64; 2] {
+    const FORMAT: BandFormat = BandFormat::Dpcomplex;
+}
+
+impl VipsRegion {
+    pub fn new(image: &VipsImage) -> Result<VipsRegion> {
+        unsafe {
+            let res = bindings::vips_region_new(image.ctx);
+            if res.is_null() {
+                Err(
+                    utils::vips_error(
+                        Error::InitializationError(
+                            "VipsRegion:new - Could not initialise VipsRegion",
+                        ),
+                    ),
+                )
+            } else {
+                Ok(
+                    VipsRegion {
+                        ctx: res,
+                    },
+                )
+            }
+        }
+    }
+
+    /// computes the pixels of the given area of the image. the area is clipped to the image bounds,
+    /// so check `left`, `top`, `width` and `height` afterwards for what is actually available
+    pub fn prepare(&mut self, left: i32, top: i32, width: i32, height: i32) -> Result<()> {
+        unsafe {
+            let rect = bindings::VipsRect {
+                left,
+                top,
+                width,
+                height,
+            };
+            let res = bindings::vips_region_prepare(
+                self.ctx,
+                &rect,
+            );
+            utils::result(
+                res,
+                || (),
+                Error::OperationError(
+                    "VipsRegion:prepare - Could not compute the pixels of the area",
+                ),
+            )
+        }
+    }
+
+    /// copies the pixels of the given area to a new buffer, packed line after line
+    pub fn fetch(&mut self, left: i32, top: i32, width: i32, height: i32) -> Result<Vec<u8>> {
+        unsafe {
+            let mut len: u64 = 0;
+            let res = bindings::vips_region_fetch(
+                self.ctx,
+                left,
+                top,
+                width,
+                height,
+                &mut len,
+            );
+            if res.is_null() {
+                Err(
+                    utils::vips_error(
+                        Error::OperationError(
+                            "VipsRegion:fetch - Could not fetch the pixels of the area",
+                        ),
+                    ),
+                )
+            } else {
+                let buf = std::slice::from_raw_parts(
+                    res,
+                    len as usize,
+                )
+                .to_vec();
+                bindings::g_free(res as *mut c_void);
+                Ok(buf)
+            }
+        }
+    }
+
+    pub fn left(&self) -> i32 {
+        unsafe {
+            (*self.ctx)
+                .valid
+                .left
+        }
+    }
+
+    pub fn top(&self) -> i32 {
+        unsafe {
+            (*self.ctx)
+                .valid
+                .top
+        }
+    }
+
+    pub fn width(&self) -> i32 {
+        unsafe {
+            (*self.ctx)
+                .valid
+                .width
+        }
+    }
+
+    pub fn height(&self) -> i32 {
+        unsafe {
+            (*self.ctx)
+                .valid
+                .height
+        }
+    }
+
+    pub fn bands(&self) -> i32 {
+        unsafe { bindings::vips_image_get_bands((*self.ctx).im) }
+    }
+
+    pub fn format(&self) -> Result<BandFormat> {
+        unsafe {
+            let res = bindings::vips_image_get_format((*self.ctx).im);
+            BandFormat::from_i32(res)
+                .ok_or(Error::IOError("VipsRegion:format - Could not get format"))
+        }
+    }
+
+    /// the band values of one prepared line of pixels. `y` is in image coordinates and the
+    /// line starts at `left`, with `width * bands` values
+    pub fn row<T: BandFormatType>(&self, y: i32) -> Result<&[T]> {
+        self.check_format::<T>()?;
+        self.line(y)
+    }
+
+    /// all the prepared lines of pixels, from top to bottom
+    pub fn rows<T: BandFormatType>(&self) -> Result<Vec<&[T]>> {
+        self.check_format::<T>()?;
+        (self.top()..self.top() + self.height())
+            .map(|y| self.line(y))
+            .collect()
+    }
+
+    /// copies the prepared pixels to a new buffer, packed line after line
+    pub fn to_vec<T: BandFormatType>(&self) -> Result<Vec<T>> {
+        Ok(self
+            .rows::<T>()?
+            .concat())
+    }
+
+    fn check_format<T: BandFormatType>(&self) -> Result<()> {
+        if self.format()? as i32 == T::FORMAT as i32 {
+            Ok(())
+        } else {
+            Err(
+                Error::OperationError(
+                    "VipsRegion:row - Pixel type does not match the band format of the image",
+                ),
+            )
+        }
+    }
+
+    // lines are bpl bytes apart, which can be more than the width of the region when it is a
+    // window onto a bigger buffer. the format of the image is checked by the caller
+    fn line<T: BandFormatType>(&self, y: i32) -> Result<&[T]> {
+        let region = unsafe { &*self.ctx };
+        if region
+            .data
+            .is_null()
+        {
+            return Err(
+                Error::OperationError("VipsRegion:row - No area of the image was prepared"),
+            );
+        }
+        if y < region
+            .valid
+            .top
+            || y >= region
+                .valid
+                .top
+                + region
+                    .valid
+                    .height
+        {
+            return Err(
+                Error::OperationError("VipsRegion:row - Line is outside of the prepared area"),
+            );
+        }
+        let line = unsafe {
+            region
+                .data
+                .offset(
+                    (y - region
+                        .valid
+                        .top) as isize
+                        * region.bpl as isize,
+                )
+        };
+        if line.align_offset(std::mem::align_of::<T>()) != 0 {
+            return Err(
+                Error::OperationError(
+                    "VipsRegion:row - Pixels are not aligned for the requested type",
+                ),
+            );
+        }
+        let len = region
+            .valid
+            .width as usize
+            * self.bands() as usize;
+        Ok(unsafe {
+            std::slice::from_raw_parts(
+                line as *const T,
+                len,
+            )
+        })
+    }
+}
+
+impl Drop for VipsRegion {
+    fn drop(&mut self) {
+        unsafe {
+            if !self
+                .ctx
+                .is_null()
+            {
+                bindings::g_object_unref(self.ctx as *mut c_void);
+            }
+        }
+    }
+}
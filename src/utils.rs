@@ -1,11 +1,23 @@
 // (c) Copyright 2019-2026 OLX
 use crate::bindings;
 use crate::bindings::{VipsArrayDouble, VipsArrayImage, VipsArrayInt};
-use crate::error::Error;
+use crate::error::{Error, ErrorDetails};
+use crate::progress;
 use crate::Result;
-use crate::VipsImage;
+use crate::{VipsBlob, VipsImage, VipsInterpolate, VipsSource, VipsTarget};
 use std::ffi::c_void;
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+
+// fundamental GLib types, defined as macros in C: G_TYPE_MAKE_FUNDAMENTAL(x) is x << G_TYPE_FUNDAMENTAL_SHIFT
+pub(crate) const G_TYPE_BOOLEAN: bindings::GType = 5 << bindings::G_TYPE_FUNDAMENTAL_SHIFT;
+pub(crate) const G_TYPE_INT: bindings::GType = 6 << bindings::G_TYPE_FUNDAMENTAL_SHIFT;
+pub(crate) const G_TYPE_UINT64: bindings::GType = 11 << bindings::G_TYPE_FUNDAMENTAL_SHIFT;
+pub(crate) const G_TYPE_ENUM: bindings::GType = 12 << bindings::G_TYPE_FUNDAMENTAL_SHIFT;
+pub(crate) const G_TYPE_FLAGS: bindings::GType = 13 << bindings::G_TYPE_FUNDAMENTAL_SHIFT;
+pub(crate) const G_TYPE_DOUBLE: bindings::GType = 15 << bindings::G_TYPE_FUNDAMENTAL_SHIFT;
+pub(crate) const G_TYPE_STRING: bindings::GType = 16 << bindings::G_TYPE_FUNDAMENTAL_SHIFT;
+pub(crate) const G_TYPE_OBJECT: bindings::GType = 20 << bindings::G_TYPE_FUNDAMENTAL_SHIFT;
 
 pub(crate) struct VipsArrayIntWrapper {
     pub ctx: *mut VipsArrayInt,
@@ -63,9 +75,239 @@ impl From<&[VipsImage]> for VipsArrayImageWrapper {
     #[inline]
     fn from(array: &[VipsImage]) -> Self {
         let len = array.len() as i32;
-        let as_vips = array.iter().map(|v| v.ctx).collect::<Vec<_>>().as_mut_ptr();
+        // the array takes its own references to the images, so the pointers only have to live
+        // until it is created
+        let mut as_vips = array.iter().map(|v| v.ctx).collect::<Vec<_>>();
         VipsArrayImageWrapper {
-            ctx: unsafe { bindings::vips_array_image_new(as_vips, len) },
+            ctx: unsafe { bindings::vips_array_image_new(as_vips.as_mut_ptr(), len) },
+        }
+    }
+}
+
+/// A libvips operation called through the object API: only the arguments that are set are passed
+/// to libvips, so the others keep the defaults of the libvips version in use.
+pub(crate) struct VipsOperationWrapper {
+    ctx: *mut bindings::VipsOperation,
+    error: fn() -> Error,
+}
+
+impl VipsOperationWrapper {
+    /// `error` builds the error returned when the operation can't be created, set or built
+    pub(crate) fn new(nickname: &str, error: fn() -> Error) -> Result<Self> {
+        let nickname = new_c_string(nickname)?;
+        let ctx = unsafe { bindings::vips_operation_new(nickname.as_ptr()) };
+        if ctx.is_null() {
+            Err(vips_error(error()))
+        } else {
+            Ok(VipsOperationWrapper { ctx, error })
+        }
+    }
+
+    pub(crate) fn object(&self) -> *mut bindings::VipsObject {
+        self.ctx as *mut bindings::VipsObject
+    }
+
+    pub(crate) fn set_int(&mut self, name: &str, value: i32) -> Result<()> {
+        let name = new_c_string(name)?;
+        let res = unsafe {
+            bindings::vips_object_set(
+                self.object(),
+                name.as_ptr(),
+                value,
+                std::ptr::null::<c_char>(),
+            )
+        };
+        result(res, || (), (self.error)())
+    }
+
+    pub(crate) fn set_bool(&mut self, name: &str, value: bool) -> Result<()> {
+        self.set_int(name, if value { 1 } else { 0 })
+    }
+
+    pub(crate) fn set_uint64(&mut self, name: &str, value: u64) -> Result<()> {
+        let name = new_c_string(name)?;
+        let res = unsafe {
+            bindings::vips_object_set(
+                self.object(),
+                name.as_ptr(),
+                value,
+                std::ptr::null::<c_char>(),
+            )
+        };
+        result(res, || (), (self.error)())
+    }
+
+    pub(crate) fn set_double(&mut self, name: &str, value: f64) -> Result<()> {
+        let name = new_c_string(name)?;
+        let res = unsafe {
+            bindings::vips_object_set(
+                self.object(),
+                name.as_ptr(),
+                value,
+                std::ptr::null::<c_char>(),
+            )
+        };
+        result(res, || (), (self.error)())
+    }
+
+    pub(crate) fn set_string(&mut self, name: &str, value: &str) -> Result<()> {
+        let value = new_c_string(value)?;
+        unsafe { self.set_pointer(name, value.as_ptr() as *mut c_void) }
+    }
+
+    pub(crate) fn set_ints(&mut self, name: &str, value: &[i32]) -> Result<()> {
+        let array = VipsArrayIntWrapper::from(value);
+        unsafe { self.set_pointer(name, array.ctx as *mut c_void) }
+    }
+
+    pub(crate) fn set_doubles(&mut self, name: &str, value: &[f64]) -> Result<()> {
+        let array = VipsArrayDoubleWrapper::from(value);
+        unsafe { self.set_pointer(name, array.ctx as *mut c_void) }
+    }
+
+    pub(crate) fn set_images(&mut self, name: &str, value: &[VipsImage]) -> Result<()> {
+        let array = VipsArrayImageWrapper::from(value);
+        unsafe { self.set_pointer(name, array.ctx as *mut c_void) }
+    }
+
+    /// the bytes are copied, as loaders can read them after the call returns
+    pub(crate) fn set_blob(&mut self, name: &str, value: &[u8]) -> Result<()> {
+        let blob = VipsBlob {
+            ctx: unsafe {
+                bindings::vips_blob_copy(value.as_ptr() as *const c_void, value.len() as u64)
+            },
+        };
+        unsafe { self.set_pointer(name, blob.ctx as *mut c_void) }
+    }
+
+    pub(crate) fn set_image(&mut self, name: &str, value: &VipsImage) -> Result<()> {
+        unsafe { self.set_pointer(name, value.ctx as *mut c_void) }
+    }
+
+    pub(crate) fn set_interpolate(&mut self, name: &str, value: &VipsInterpolate) -> Result<()> {
+        unsafe { self.set_pointer(name, value.ctx as *mut c_void) }
+    }
+
+    pub(crate) fn set_source(&mut self, name: &str, value: &VipsSource) -> Result<()> {
+        unsafe { self.set_pointer(name, value.ctx as *mut c_void) }
+    }
+
+    pub(crate) fn set_target(&mut self, name: &str, value: &VipsTarget) -> Result<()> {
+        unsafe { self.set_pointer(name, value.ctx as *mut c_void) }
+    }
+
+    // strings, boxed arrays and objects are copied or referenced by libvips while setting them
+    unsafe fn set_pointer(&mut self, name: &str, value: *mut c_void) -> Result<()> {
+        let name = new_c_string(name)?;
+        let res = bindings::vips_object_set(
+            self.object(),
+            name.as_ptr(),
+            value,
+            std::ptr::null::<c_char>(),
+        );
+        result(res, || (), (self.error)())
+    }
+
+    /// builds the operation, or takes the matching one from the libvips operation cache
+    pub(crate) fn build(&mut self) -> Result<()> {
+        let res = unsafe { bindings::vips_cache_operation_buildp(&mut self.ctx) };
+        result(res, || (), (self.error)())
+    }
+
+    /// builds the operation outside of the libvips operation cache, so its outputs are new
+    /// objects no other caller can be given
+    pub(crate) fn build_uncached(&mut self) -> Result<()> {
+        let res = unsafe { bindings::vips_object_build(self.object()) };
+        result(res, || (), (self.error)())
+    }
+
+    pub(crate) fn get_int(&self, name: &str) -> Result<i32> {
+        let mut value: i32 = 0;
+        unsafe { self.get(name, &mut value)? };
+        Ok(value)
+    }
+
+    pub(crate) fn get_bool(&self, name: &str) -> Result<bool> {
+        Ok(self.get_int(name)? != 0)
+    }
+
+    pub(crate) fn get_uint64(&self, name: &str) -> Result<u64> {
+        let mut value: u64 = 0;
+        unsafe { self.get(name, &mut value)? };
+        Ok(value)
+    }
+
+    pub(crate) fn get_double(&self, name: &str) -> Result<f64> {
+        let mut value: f64 = 0.0;
+        unsafe { self.get(name, &mut value)? };
+        Ok(value)
+    }
+
+    pub(crate) fn get_string(&self, name: &str) -> Result<Option<String>> {
+        let mut value: *mut c_char = std::ptr::null_mut();
+        unsafe {
+            self.get(name, &mut value)?;
+            Ok(new_optional_string(value))
+        }
+    }
+
+    pub(crate) fn get_ints(&self, name: &str) -> Result<Vec<i32>> {
+        let mut value: *mut VipsArrayInt = std::ptr::null_mut();
+        unsafe {
+            self.get(name, &mut value)?;
+            Ok(new_int_array_output(value))
+        }
+    }
+
+    pub(crate) fn get_doubles(&self, name: &str) -> Result<Vec<f64>> {
+        let mut value: *mut VipsArrayDouble = std::ptr::null_mut();
+        unsafe {
+            self.get(name, &mut value)?;
+            Ok(new_double_array_output(value))
+        }
+    }
+
+    pub(crate) fn get_blob(&self, name: &str) -> Result<Vec<u8>> {
+        let mut value: *mut bindings::VipsBlob = std::ptr::null_mut();
+        unsafe { self.get(name, &mut value)? };
+        if value.is_null() {
+            Ok(Vec::new())
+        } else {
+            Ok(VipsBlob { ctx: value }.into())
+        }
+    }
+
+    pub(crate) fn get_image(&self, name: &str) -> Result<VipsImage> {
+        self.get_optional_image(name)?
+            .ok_or_else(|| vips_error((self.error)()))
+    }
+
+    pub(crate) fn get_optional_image(&self, name: &str) -> Result<Option<VipsImage>> {
+        let mut value: *mut bindings::VipsImage = std::ptr::null_mut();
+        unsafe {
+            self.get(name, &mut value)?;
+            Ok(new_optional_image(value))
+        }
+    }
+
+    // objects, boxed values and strings read this way are owned by the caller
+    unsafe fn get<T>(&self, name: &str, value: *mut T) -> Result<()> {
+        let name = new_c_string(name)?;
+        bindings::g_object_get(
+            self.ctx as bindings::gpointer,
+            name.as_ptr(),
+            value,
+            std::ptr::null::<c_char>(),
+        );
+        Ok(())
+    }
+}
+
+impl Drop for VipsOperationWrapper {
+    fn drop(&mut self) {
+        unsafe {
+            bindings::vips_object_unref_outputs(self.object());
+            bindings::g_object_unref(self.ctx as bindings::gpointer);
         }
     }
 }
@@ -77,13 +319,208 @@ impl From<&[VipsImage]> for VipsArrayImageWrapper {
 /// `*save_buffer` where the output pointer is left null on failure —
 /// eagerly constructing a `Vec` via `from_raw_parts(null, …)` would be
 /// undefined behavior.
+///
+/// On failure the libvips error buffer is drained into the returned error. The buffer is
+/// shared by all threads, so this is best-effort: when calls fail on several threads at the
+/// same time, a message can end up in the error of another of these calls.
 #[inline]
 pub fn result<T>(res: i32, output: impl FnOnce() -> T, error: Error) -> Result<T> {
     if res == 0 {
         Ok(output())
     } else {
-        Err(error)
+        Err(vips_error(error))
+    }
+}
+
+/// Wraps `error` with the contents of the libvips error buffer, which is cleared in the process.
+/// Failures of blocked operations are returned as `Error::Blocked`.
+pub(crate) fn vips_error(error: Error) -> Error {
+    let message = error_message();
+    let operation = error.operation_nickname().map(String::from);
+    if operation.as_deref().is_some_and(is_blocked) {
+        return Error::Blocked;
+    }
+    Error::VipsError {
+        kind: Box::new(error),
+        details: ErrorDetails {
+            message,
+            operation,
+            filename: None,
+        },
+    }
+}
+
+/// Drains the libvips error buffer, for the failures reported without an `Error`.
+pub(crate) fn error_message() -> String {
+    unsafe {
+        let buffer = bindings::vips_error_buffer_copy();
+        if buffer.is_null() {
+            String::new()
+        } else {
+            let message = CStr::from_ptr(buffer).to_string_lossy().into_owned();
+            bindings::g_free(buffer as *mut c_void);
+            message
+        }
+    }
+}
+
+/// Attaches the filename of the input image to an error returned by `vips_error`.
+pub(crate) fn with_filename(error: Error, filename: &str) -> Error {
+    match error {
+        Error::VipsError { kind, mut details } => {
+            details.filename = Some(filename.to_string());
+            Error::VipsError { kind, details }
+        }
+        error => error,
+    }
+}
+
+/// Replaces an error returned by `vips_error` with `Error::Cancelled` when the progress callback of
+/// the image cancelled its evaluation.
+pub(crate) fn with_cancelled(error: Error, image: &VipsImage) -> Error {
+    if progress::cancelled(image) {
+        Error::Cancelled
+    } else {
+        error
+    }
+}
+
+/// Applies `with_cancelled`, then attaches the filename of the input image to the error, when it was
+/// loaded from a file. The other images only have the temporary name libvips gives them.
+pub(crate) fn with_input_image(error: Error, image: &VipsImage) -> Error {
+    let error = with_cancelled(error, image);
+    let filename = unsafe { bindings::vips_image_get_filename(image.ctx) };
+    if filename.is_null() {
+        return error;
+    }
+    match unsafe { CStr::from_ptr(filename) }.to_str() {
+        Ok(filename) if !filename.is_empty() && !filename.starts_with("temp-") => {
+            with_filename(error, filename)
+        }
+        _ => error,
+    }
+}
+
+/// Attaches the nickname of an operation called by name to an error returned by `vips_error`, or
+/// returns `Error::Blocked` when the operation is blocked.
+pub(crate) fn with_operation(error: Error, nickname: &str) -> Error {
+    match error {
+        Error::VipsError { .. } if is_blocked(nickname) => Error::Blocked,
+        Error::VipsError { kind, mut details } => {
+            details.operation = Some(nickname.to_string());
+            Error::VipsError { kind, details }
+        }
+        error => error,
+    }
+}
+
+/// libvips only tries the loaders that are not blocked when it picks one, so content only a blocked
+/// loader supports fails as unknown. The sniffers of the blocked loaders parse the content, so they
+/// are not run to tell the two apart: the loader `find_load` picks among the allowed ones is
+/// compared against the blocked types by nickname, and when there is none while loaders of the same
+/// kind are blocked, the error becomes `Error::Blocked`. `find_load` calls one of the
+/// `vips_foreign_find_load*` functions and `kind` is the suffix of the nicknames of the loaders it
+/// tries, `"_buffer"`, `"_source"` or `""` for the file loaders.
+pub(crate) fn with_blocked_loader(
+    error: Error,
+    kind: &str,
+    find_load: impl FnOnce() -> *const c_char,
+) -> Error {
+    if !matches!(error, Error::VipsError { .. }) {
+        return error;
+    }
+    let loader = find_load();
+    let blocked = if loader.is_null() {
+        concrete_types(unsafe { bindings::vips_foreign_load_get_type() })
+            .into_iter()
+            .any(|gtype| {
+                type_nickname(gtype).is_some_and(|nickname| loader_kind(&nickname) == kind)
+                    && is_blocked_type(gtype)
+            })
+    } else {
+        // the loader was allowed when it was picked, unless the policy changed since
+        is_blocked_type(unsafe { bindings::g_type_from_name(loader) })
+    };
+    if blocked {
+        Error::Blocked
+    } else {
+        error
+    }
+}
+
+// the suffix of the nickname telling what a loader reads, e.g. `"_buffer"` for `pngload_buffer`
+fn loader_kind(nickname: &str) -> &str {
+    ["_buffer", "_source"]
+        .into_iter()
+        .find(|kind| nickname.ends_with(kind))
+        .unwrap_or_default()
+}
+
+fn type_nickname(gtype: bindings::GType) -> Option<String> {
+    unsafe {
+        let nickname = bindings::vips_nickname_find(gtype);
+        if nickname.is_null() {
+            None
+        } else {
+            Some(CStr::from_ptr(nickname).to_string_lossy().into_owned())
+        }
+    }
+}
+
+/// Whether the operation with this nickname is blocked, by the flag libvips sets on its class.
+pub(crate) fn is_blocked(nickname: &str) -> bool {
+    let (Ok(base), Ok(nickname)) = (new_c_string("VipsOperation"), new_c_string(nickname)) else {
+        return false;
+    };
+    is_blocked_type(unsafe { bindings::vips_type_find(base.as_ptr(), nickname.as_ptr()) })
+}
+
+fn is_blocked_type(gtype: bindings::GType) -> bool {
+    gtype != 0
+        && operation_class_flags(gtype) & bindings::VipsOperationFlags_VIPS_OPERATION_BLOCKED != 0
+}
+
+/// The types derived from `parent`, skipping the abstract ones.
+pub(crate) fn concrete_types(parent: bindings::GType) -> Vec<bindings::GType> {
+    let mut types: Vec<bindings::GType> = Vec::new();
+    unsafe {
+        bindings::vips_type_map_all(
+            parent,
+            Some(collect_type),
+            &mut types as *mut Vec<bindings::GType> as *mut c_void,
+        );
     }
+    types.retain(|gtype| unsafe {
+        bindings::g_type_test_flags(*gtype, bindings::GTypeFlags_G_TYPE_FLAG_ABSTRACT) == 0
+    });
+    types
+}
+
+unsafe extern "C" fn collect_type(gtype: bindings::GType, a: *mut c_void) -> *mut c_void {
+    (*(a as *mut Vec<bindings::GType>)).push(gtype);
+    std::ptr::null_mut()
+}
+
+/// The flags an operation type sets on its class, read without creating an operation.
+pub(crate) fn operation_class_flags(gtype: bindings::GType) -> bindings::VipsOperationFlags {
+    unsafe {
+        let class = bindings::g_type_class_ref(gtype) as *mut bindings::VipsOperationClass;
+        let flags = (*class).flags;
+        bindings::g_type_class_unref(class as *mut c_void);
+        flags
+    }
+}
+
+/// Connects a C handler to a signal of a libvips object. `destroy` is called with `data` when the
+/// handler is disconnected, at the latest when the object is finalized.
+pub(crate) unsafe fn signal_connect(
+    instance: *mut c_void,
+    signal: &CStr,
+    handler: bindings::GCallback,
+    data: *mut c_void,
+    destroy: bindings::GClosureNotify,
+) -> bindings::gulong {
+    bindings::g_signal_connect_data(instance, signal.as_ptr(), handler, data, destroy, 0)
 }
 
 #[inline]
@@ -105,3 +542,162 @@ pub unsafe fn new_int_array(array: *mut i32, size: u64) -> Vec<i32> {
 pub unsafe fn new_double_array(array: *mut f64, size: u64) -> Vec<f64> {
     Vec::from(std::slice::from_raw_parts(array as *mut f64, size as usize))
 }
+
+// optional outputs are owned by the caller and left null when libvips didn't produce them
+
+#[inline]
+pub(crate) unsafe fn new_optional_image(image: *mut bindings::VipsImage) -> Option<VipsImage> {
+    if image.is_null() {
+        None
+    } else {
+        Some(VipsImage { ctx: image })
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn new_optional_string(string: *mut c_char) -> Option<String> {
+    if string.is_null() {
+        None
+    } else {
+        let res = CStr::from_ptr(string).to_string_lossy().into_owned();
+        bindings::g_free(string as *mut c_void);
+        Some(res)
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn new_int_array_output(array: *mut VipsArrayInt) -> Vec<i32> {
+    if array.is_null() {
+        return Vec::new();
+    }
+    let mut size: i32 = 0;
+    let values = bindings::vips_array_int_get(array, &mut size);
+    let res = if values.is_null() {
+        Vec::new()
+    } else {
+        new_int_array(values, size as u64)
+    };
+    bindings::vips_area_unref(array as *mut bindings::VipsArea);
+    res
+}
+
+#[inline]
+pub(crate) unsafe fn new_double_array_output(array: *mut VipsArrayDouble) -> Vec<f64> {
+    if array.is_null() {
+        return Vec::new();
+    }
+    let mut size: i32 = 0;
+    let values = bindings::vips_array_double_get(array, &mut size);
+    let res = if values.is_null() {
+        Vec::new()
+    } else {
+        new_double_array(values, size as u64)
+    };
+    bindings::vips_area_unref(array as *mut bindings::VipsArea);
+    res
+}
+
+/// Checks the header of a loaded image against the `LoadPolicy` set on the `VipsApp`.
+pub(crate) fn check_load(image: &VipsImage) -> Result<()> {
+    crate::policy::load_policy().check(image)
+}
+
+/// `check_load` for the result of a loader, which is dropped before its pixels are decoded when
+/// it exceeds the policy.
+pub(crate) fn checked_load(image: VipsImage) -> Result<VipsImage> {
+    check_load(&image).map(|()| image)
+}
+
+/// Checks the size of a buffer given to a loader against the `LoadPolicy`.
+pub(crate) fn check_load_bytes(len: usize) -> Result<()> {
+    crate::policy::load_policy().check_bytes(len as u64)
+}
+
+/// Checks the size of a file given to a loader against the `LoadPolicy`. A file that can't be read
+/// is left to the loader to report.
+pub(crate) fn check_load_file_bytes(filename: &str) -> Result<()> {
+    let policy = crate::policy::load_policy();
+    if !policy.limits_bytes() {
+        return Ok(());
+    }
+    // the loaders are given the filename with the load options
+    let filename = filename.split('[').next().unwrap_or_default();
+    match std::fs::metadata(filename) {
+        Ok(metadata) => policy.check_bytes(metadata.len()),
+        Err(_) => Ok(()),
+    }
+}
+
+/// Checks the length of a source given to a loader against the `LoadPolicy`. Unseekable sources
+/// only know their length once they are read to the end, so they are left unchecked here: the ones
+/// created from a Rust reader fail their reads past the limit instead, see `with_source_limit`.
+pub(crate) fn check_load_source_bytes(source: &VipsSource) -> Result<()> {
+    let policy = crate::policy::load_policy();
+    if !policy.limits_bytes() {
+        return Ok(());
+    }
+    unsafe {
+        // the first seek tells libvips if the source is a pipe, without reading from it
+        if bindings::vips_source_seek(source.ctx, 0, bindings::SEEK_CUR as i32) < 0
+            || (*source.ctx).is_pipe != 0
+        {
+            return Ok(());
+        }
+        let length = bindings::vips_source_length(source.ctx);
+        if length < 0 {
+            return Ok(());
+        }
+        policy.check_bytes(length as u64)
+    }
+}
+
+/// Replaces the error of a load from a source created with `VipsSource::from_unseekable_reader`
+/// with `Error::LimitExceeded` when the source was read past the byte limit of the `LoadPolicy`.
+pub(crate) fn with_source_limit(error: Error, source: &VipsSource) -> Error {
+    match source
+        .bytes_read()
+        .map(|read| crate::policy::load_policy().check_bytes(read))
+    {
+        Some(Err(limit)) => limit,
+        _ => error,
+    }
+}
+
+/// The thumbnail operations load and shrink in a single call, so the header is loaded on its own
+/// first. Only the limits are reported here, other errors are left to the thumbnail itself.
+fn check_load_header(load: impl FnOnce() -> Result<VipsImage>) -> Result<()> {
+    if crate::policy::load_policy().is_unlimited() {
+        return Ok(());
+    }
+    match load() {
+        Err(e @ Error::LimitExceeded { .. }) => Err(e),
+        _ => Ok(()),
+    }
+}
+
+pub(crate) fn check_load_file(filename: &str) -> Result<()> {
+    check_load_header(|| VipsImage::new_from_file(filename))
+}
+
+pub(crate) fn check_load_buffer(buffer: &[u8]) -> Result<()> {
+    check_load_header(|| VipsImage::new_from_buffer(buffer, ""))
+}
+
+/// The header is read from the source the thumbnail then loads. libvips keeps the bytes it reads
+/// from an unseekable source until the pixels are decoded, so the source is rewound to its start
+/// for the thumbnail, pipes included.
+pub(crate) fn check_load_source(source: &VipsSource) -> Result<()> {
+    if crate::policy::load_policy().is_unlimited() {
+        return Ok(());
+    }
+    check_load_header(|| VipsImage::new_from_source(source, ""))?;
+    unsafe {
+        result(
+            bindings::vips_source_rewind(source.ctx),
+            || (),
+            Error::IOError(
+                "VipsSource:rewind - Could not rewind the source after reading its header",
+            ),
+        )
+    }
+}
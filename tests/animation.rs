@@ -0,0 +1,198 @@
+// (c) Copyright 2019-2026 OLX
+mod common;
+
+use libvips::{ops, Animation, VipsImage};
+
+fn test_image_path() -> String {
+    format!(
+        "{}/examples/test.png",
+        env!("CARGO_MANIFEST_DIR")
+    )
+}
+
+// three 4x4 frames shown for 100, 200 and 300ms, looping forever
+fn animated_gif_path() -> String {
+    format!(
+        "{}/examples/animated.gif",
+        env!("CARGO_MANIFEST_DIR")
+    )
+}
+
+#[test]
+fn frames_survive_a_round_trip_through_the_tall_image() {
+    let _app = common::app();
+    let image = VipsImage::new_from_file(&test_image_path()).unwrap();
+    let frames = vec![image.clone(), ops::invert(&image).unwrap(), image.clone()];
+    let animation = Animation::from_frames(
+        frames,
+        vec![40, 80, 120],
+        3,
+    )
+    .unwrap();
+
+    let resized = animation
+        .map(|frame| ops::resize(frame, 0.5))
+        .unwrap();
+    let joined = resized
+        .to_image()
+        .unwrap();
+    let frame_height = resized.frames()[0].get_height();
+    assert_eq!(
+        joined.get_page_height(),
+        frame_height
+    );
+    assert_eq!(
+        joined.get_height(),
+        frame_height * 3
+    );
+    assert_eq!(
+        joined
+            .get_array_int("delay")
+            .unwrap(),
+        vec![40, 80, 120]
+    );
+    assert_eq!(
+        joined
+            .get_int("loop")
+            .unwrap(),
+        3
+    );
+
+    let split = Animation::from_image(&joined).unwrap();
+    assert_eq!(
+        split
+            .frames()
+            .len(),
+        3
+    );
+    assert_eq!(
+        split.delays(),
+        &[40, 80, 120]
+    );
+    assert_eq!(
+        split.loop_count(),
+        3
+    );
+
+    // frames of different sizes can't be joined
+    let mut i = 0;
+    let uneven = animation
+        .map(|frame| {
+            i += 1;
+            ops::resize(
+                frame,
+                if i == 2 { 0.25 } else { 0.5 },
+            )
+        })
+        .unwrap();
+    assert!(uneven
+        .to_image()
+        .is_err());
+    assert!(
+        Animation::from_frames(
+            vec![image],
+            vec![],
+            0
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn animations_survive_a_round_trip_through_a_gif() {
+    let _app = common::app();
+    let animation = Animation::new_from_file(&animated_gif_path()).unwrap();
+    assert_eq!(
+        animation
+            .frames()
+            .len(),
+        3
+    );
+    assert_eq!(
+        animation.delays(),
+        &[100, 200, 300]
+    );
+    assert_eq!(
+        animation.loop_count(),
+        0
+    );
+    assert_eq!(
+        animation.frames()[0].get_width(),
+        4
+    );
+    assert_eq!(
+        animation.frames()[0].get_height(),
+        4
+    );
+
+    // load options in the filename are kept
+    let sequential = Animation::new_from_file(&format!(
+        "{}[access=sequential]",
+        animated_gif_path()
+    ))
+    .unwrap();
+    assert_eq!(
+        sequential
+            .frames()
+            .len(),
+        3
+    );
+    let first = Animation::new_from_file(&format!(
+        "{}[n=1]",
+        animated_gif_path()
+    ))
+    .unwrap();
+    assert_eq!(
+        first
+            .frames()
+            .len(),
+        1
+    );
+
+    let mut edited = animation.clone();
+    edited
+        .set_delays(&[50, 60, 70])
+        .unwrap();
+    edited.set_loop_count(2);
+    let saved = ops::gifsave_buffer(
+        &edited
+            .to_image()
+            .unwrap(),
+    )
+    .unwrap();
+    let loaded = Animation::new_from_buffer(&saved).unwrap();
+    assert_eq!(
+        loaded
+            .frames()
+            .len(),
+        3
+    );
+    assert_eq!(
+        loaded.delays(),
+        &[50, 60, 70]
+    );
+    assert_eq!(
+        loaded.loop_count(),
+        2
+    );
+
+    // joining the same frames twice must not share the fields of the joined images
+    let image = animation
+        .to_image()
+        .unwrap();
+    let _other = edited
+        .to_image()
+        .unwrap();
+    assert_eq!(
+        image
+            .get_array_int("delay")
+            .unwrap(),
+        vec![100, 200, 300]
+    );
+    assert_eq!(
+        image
+            .get_int("loop")
+            .unwrap(),
+        0
+    );
+}
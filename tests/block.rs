@@ -0,0 +1,170 @@
+// (c) Copyright 2019-2026 OLX
+mod common;
+
+use libvips::error::Error;
+use libvips::{ops, BlockPolicy, VipsImage};
+
+fn test_image_path() -> String {
+    format!(
+        "{}/examples/test.png",
+        env!("CARGO_MANIFEST_DIR")
+    )
+}
+
+fn assert_blocked<T>(result: libvips::Result<T>, scenario: &str) {
+    match result {
+        Err(Error::Blocked) => (),
+        Err(e) => panic!(
+            "{} failed with {} instead of Blocked",
+            scenario, e
+        ),
+        Ok(_) => panic!(
+            "{} was not blocked",
+            scenario
+        ),
+    }
+}
+
+#[test]
+fn blocked_loaders_fail_with_blocked() {
+    let app = common::app();
+    // a cached result would be returned without building the operation again
+    let cache_max = app.cache_get_max();
+    app.cache_set_max(0);
+    let buffer = std::fs::read(test_image_path()).unwrap();
+
+    // blocking the base type blocks the file, buffer and source loaders
+    app.block_policy_set(
+        &BlockPolicy::new()
+            .block_untrusted(true)
+            .deny("VipsForeignLoadPng"),
+    )
+    .unwrap();
+    assert_blocked(
+        VipsImage::new_from_file(&test_image_path()),
+        "new_from_file",
+    );
+    assert_blocked(
+        VipsImage::new_from_buffer(
+            &buffer,
+            "",
+        ),
+        "new_from_buffer",
+    );
+    assert_blocked(
+        ops::pngload(&test_image_path()),
+        "pngload",
+    );
+    assert_blocked(
+        ops::pngload_buffer(&buffer),
+        "pngload_buffer",
+    );
+
+    // the allowed loader is unblocked, the others of the family stay blocked
+    app.block_policy_set(
+        &BlockPolicy::new()
+            .block_untrusted(true)
+            .deny("VipsForeignLoadPng")
+            .allow("pngload"),
+    )
+    .unwrap();
+    assert!(
+        VipsImage::new_from_file(&test_image_path())
+            .unwrap()
+            .get_width()
+            > 0
+    );
+    assert_blocked(
+        VipsImage::new_from_buffer(
+            &buffer,
+            "",
+        ),
+        "new_from_buffer",
+    );
+
+    app.operation_block_set(
+        "VipsForeignLoadPng",
+        false,
+    )
+    .unwrap();
+    app.block_untrusted_set(false);
+    assert!(
+        VipsImage::new_from_buffer(
+            &buffer,
+            ""
+        )
+        .unwrap()
+        .get_width()
+            > 0
+    );
+
+    assert!(app
+        .operation_block_set(
+            "nosuchload",
+            true
+        )
+        .is_err());
+    app.block_policy_set(&BlockPolicy::new())
+        .unwrap();
+    app.cache_set_max(cache_max);
+}
+
+#[test]
+fn block_policies_replace_each_other() {
+    let app = common::app();
+    let cache_max = app.cache_get_max();
+    app.cache_set_max(0);
+    let buffer = std::fs::read(test_image_path()).unwrap();
+
+    app.block_policy_set(&BlockPolicy::new().deny("VipsForeignLoadPng"))
+        .unwrap();
+    assert_blocked(
+        VipsImage::new_from_buffer(
+            &buffer,
+            "",
+        ),
+        "new_from_buffer",
+    );
+    assert_blocked(
+        ops::pngload_buffer(&buffer),
+        "pngload_buffer",
+    );
+
+    // the png loaders denied by the previous policy are allowed again
+    app.block_policy_set(&BlockPolicy::new().deny("jpegload_buffer"))
+        .unwrap();
+    assert!(
+        VipsImage::new_from_buffer(
+            &buffer,
+            ""
+        )
+        .unwrap()
+        .get_width()
+            > 0
+    );
+    assert!(
+        ops::pngload_buffer(&buffer)
+            .unwrap()
+            .get_width()
+            > 0
+    );
+
+    // an unknown name leaves the current policy in place
+    assert!(app
+        .block_policy_set(&BlockPolicy::new().deny("nosuchload"))
+        .is_err());
+    assert_blocked(
+        ops::jpegload_buffer(&buffer),
+        "jpegload_buffer",
+    );
+
+    app.block_policy_set(&BlockPolicy::new())
+        .unwrap();
+    assert!(
+        ops::pngload_buffer(&buffer)
+            .unwrap()
+            .get_width()
+            > 0
+    );
+    app.cache_set_max(cache_max);
+}
@@ -0,0 +1,300 @@
+// (c) Copyright 2019-2026 OLX
+mod common;
+
+use libvips::{bindings, ops, SharedImage, VipsImage, VipsInterpolate, VipsSource, VipsTarget};
+use std::ffi::c_void;
+use std::ptr::null_mut;
+
+// libvips keeps every live VipsObject in a global table. counting them before and after
+// a scenario tells us if a clone leaked a reference, while a double unref would abort the test.
+unsafe extern "C" fn count_object(_: *mut c_void, a: *mut c_void, _: *mut c_void) -> *mut c_void {
+    *(a as *mut i32) += 1;
+    null_mut()
+}
+
+fn live_objects() -> i32 {
+    let mut count = 0;
+    unsafe {
+        bindings::vips_object_map(
+            Some(count_object),
+            &mut count as *mut i32 as *mut c_void,
+            null_mut(),
+        );
+    }
+    count
+}
+
+fn test_image_path() -> String {
+    format!(
+        "{}/examples/test.png",
+        env!("CARGO_MANIFEST_DIR")
+    )
+}
+
+fn clone_image_drop_original_first() {
+    let image = VipsImage::new_from_file(&test_image_path()).unwrap();
+    let cloned = image.clone();
+    drop(image);
+    assert!(cloned.get_width() > 0);
+    let resized = ops::resize(
+        &cloned,
+        0.5,
+    )
+    .unwrap();
+    assert_eq!(
+        resized.get_width(),
+        (cloned.get_width() as f64 * 0.5).round() as i32
+    );
+}
+
+fn clone_image_drop_clone_first() {
+    let image = VipsImage::new_from_file(&test_image_path()).unwrap();
+    let cloned = image.clone();
+    drop(cloned);
+    let resized = ops::resize(
+        &image,
+        0.5,
+    )
+    .unwrap();
+    assert!(resized.get_width() > 0);
+}
+
+fn clone_image_many_times() {
+    let image = VipsImage::new_from_file(&test_image_path()).unwrap();
+    let clones: Vec<VipsImage> = (0..16)
+        .map(|_| image.clone())
+        .collect();
+    drop(image);
+    for (i, cloned) in clones
+        .into_iter()
+        .enumerate()
+    {
+        if i % 2 == 0 {
+            let copy = cloned.clone();
+            drop(cloned);
+            assert!(copy.get_height() > 0);
+        } else {
+            assert!(cloned.get_height() > 0);
+        }
+    }
+}
+
+fn clone_options_holding_objects() {
+    let image = VipsImage::new_from_file(&test_image_path()).unwrap();
+    let interpolate = VipsInterpolate::new_from_name("bicubic").unwrap();
+    let options = ops::SimilarityOptions::new()
+        .scale(0.5)
+        .interpolate(&interpolate);
+    drop(interpolate);
+    let cloned_options = options.clone();
+    drop(options);
+    let scaled = ops::similarity_with_opts(
+        &image,
+        &cloned_options,
+    )
+    .unwrap();
+    assert!(scaled.get_width() < image.get_width());
+}
+
+fn clone_source_and_target() {
+    let buffer = std::fs::read(test_image_path()).unwrap();
+    let source = VipsSource::new_from_memory(&buffer).unwrap();
+    let cloned = source.clone();
+    drop(source);
+    let image = ops::pngload_source(&cloned).unwrap();
+    drop(cloned);
+    assert!(image.get_width() > 0);
+
+    let target = VipsTarget::new_to_memory().unwrap();
+    let cloned = target.clone();
+    drop(target);
+    ops::pngsave_target(
+        &image,
+        &cloned,
+    )
+    .unwrap();
+    assert!(!cloned
+        .steal()
+        .unwrap()
+        .is_empty());
+}
+
+fn clone_interpolate() {
+    let interpolate = VipsInterpolate::new_from_name("bicubic").unwrap();
+    let cloned = interpolate.clone();
+    drop(interpolate);
+    assert!(cloned.get_window_size() > 0);
+
+    // the static interpolators are shared with libvips and must survive our drops
+    for _ in 0..4 {
+        let nearest = VipsInterpolate::new();
+        drop(nearest.clone());
+    }
+    assert!(VipsInterpolate::new_from_bilinear_static().get_window_size() > 0);
+}
+
+#[test]
+fn clones_share_references_without_leaking() {
+    let app = common::app();
+    // cached operations hold references to their inputs, which would hide leaks
+    let cache_max = app.cache_get_max();
+    app.cache_set_max(0);
+    // the static interpolators are created on first use and live until shutdown
+    drop(VipsInterpolate::new_from_neasest_static());
+    drop(VipsInterpolate::new_from_bilinear_static());
+
+    let scenarios: [(&str, fn()); 6] = [
+        (
+            "clone_image_drop_original_first",
+            clone_image_drop_original_first,
+        ),
+        (
+            "clone_image_drop_clone_first",
+            clone_image_drop_clone_first,
+        ),
+        (
+            "clone_image_many_times",
+            clone_image_many_times,
+        ),
+        (
+            "clone_options_holding_objects",
+            clone_options_holding_objects,
+        ),
+        (
+            "clone_source_and_target",
+            clone_source_and_target,
+        ),
+        (
+            "clone_interpolate",
+            clone_interpolate,
+        ),
+    ];
+    let leaks: Vec<(&str, i32)> = scenarios
+        .into_iter()
+        .map(
+            |(name, scenario)| {
+                let before = live_objects();
+                scenario();
+                (
+                    name,
+                    live_objects() - before,
+                )
+            },
+        )
+        .filter(|(_, leaked)| *leaked != 0)
+        .collect();
+    app.cache_set_max(cache_max);
+    assert!(
+        leaks.is_empty(),
+        "leaked vips objects: {:?}",
+        leaks
+    );
+}
+
+#[test]
+fn drawing_on_a_clone_leaves_the_shared_image_unchanged() {
+    let _app = common::app();
+    let black = ops::black(16, 16).unwrap();
+    let shared = SharedImage::new(VipsImage::image_copy_memory(black).unwrap());
+    let other = shared.clone();
+
+    // the clone of the image behind the handle shares it until it is drawn on
+    let mut drawn = (*shared).clone();
+    ops::draw_rect(
+        &mut drawn,
+        &mut [255.0],
+        0,
+        0,
+        8,
+        8,
+    )
+    .unwrap();
+    assert_eq!(
+        ops::getpoint(
+            &drawn,
+            0,
+            0
+        )
+        .unwrap(),
+        vec![255.0]
+    );
+    assert_eq!(
+        ops::getpoint(
+            &shared,
+            0,
+            0
+        )
+        .unwrap(),
+        vec![0.0]
+    );
+    assert_eq!(
+        ops::getpoint(
+            &other,
+            0,
+            0
+        )
+        .unwrap(),
+        vec![0.0]
+    );
+
+    // a clone taken from another thread doesn't alias the shared image either
+    let handle = std::thread::spawn(move || {
+        let mut drawn = (*other).clone();
+        ops::draw_rect(
+            &mut drawn,
+            &mut [128.0],
+            0,
+            0,
+            16,
+            16,
+        )
+        .unwrap();
+        ops::getpoint(
+            &drawn,
+            4,
+            4,
+        )
+        .unwrap()
+    });
+    assert_eq!(
+        handle
+            .join()
+            .unwrap(),
+        vec![128.0]
+    );
+    assert_eq!(
+        ops::avg(&shared).unwrap(),
+        0.0
+    );
+
+    // images computed from a drawn image keep the pixels they were computed from
+    let mut image = VipsImage::image_copy_memory(ops::black(16, 16).unwrap()).unwrap();
+    let inverted = ops::invert(&image).unwrap();
+    ops::draw_rect(
+        &mut image,
+        &mut [255.0],
+        0,
+        0,
+        16,
+        16,
+    )
+    .unwrap();
+    assert_eq!(
+        ops::getpoint(
+            &image,
+            0,
+            0
+        )
+        .unwrap(),
+        vec![255.0]
+    );
+    assert_eq!(
+        ops::getpoint(
+            &inverted,
+            0,
+            0
+        )
+        .unwrap(),
+        vec![255.0]
+    );
+}
@@ -0,0 +1,41 @@
+// (c) Copyright 2019-2026 OLX
+use libvips::VipsApp;
+use std::ops::Deref;
+use std::sync::{Mutex, MutexGuard, OnceLock};
+
+/// libvips started once for the whole test binary: dropping a `VipsApp` shuts libvips down, which
+/// would break the tests still running on the other threads. The tests of a binary also share the
+/// policies, the operation cache and the table of live objects, so they run one at a time, each
+/// holding the `TestApp` it got from `app`.
+pub struct TestApp {
+    app: &'static VipsApp,
+    _serial: MutexGuard<'static, ()>,
+}
+
+impl Deref for TestApp {
+    type Target = VipsApp;
+
+    fn deref(&self) -> &VipsApp {
+        self.app
+    }
+}
+
+pub fn app() -> TestApp {
+    static APP: OnceLock<VipsApp> = OnceLock::new();
+    static SERIAL: Mutex<()> = Mutex::new(());
+    // a failed test poisons the lock, which must not fail the next ones
+    let serial = SERIAL
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    let app = APP.get_or_init(|| {
+        VipsApp::new(
+            "libvips tests",
+            false,
+        )
+        .expect("Cannot initialize libvips")
+    });
+    TestApp {
+        app,
+        _serial: serial,
+    }
+}
@@ -0,0 +1,175 @@
+// (c) Copyright 2019-2026 OLX
+mod common;
+
+use libvips::introspection::{self, ArgumentDirection, ImageFormat};
+use libvips::{ops, VipsImage, VipsSource};
+use std::io::Cursor;
+
+#[test]
+fn operations_are_listed_with_their_arguments() {
+    let _app = common::app();
+    let operations = introspection::operations();
+    for nickname in [
+        "invert",
+        "draw_rect",
+        "pngload",
+        "pngsave_buffer",
+        "thumbnail_source",
+    ] {
+        assert!(
+            operations
+                .iter()
+                .any(|listed| listed == nickname),
+            "{} is not listed",
+            nickname
+        );
+    }
+    // the abstract types have no nickname of their own
+    assert!(
+        !operations
+            .iter()
+            .any(|listed| listed == "foreign")
+    );
+
+    let invert = introspection::operation("invert").unwrap();
+    assert_eq!(
+        invert.type_name,
+        "VipsInvert"
+    );
+    let input = invert
+        .arguments
+        .iter()
+        .find(|argument| argument.name == "in")
+        .unwrap();
+    assert_eq!(
+        input.type_name,
+        "VipsImage"
+    );
+    assert_eq!(
+        input.direction,
+        ArgumentDirection::Input
+    );
+    assert!(input.required);
+
+    let draw_rect = introspection::operation("draw_rect").unwrap();
+    let image = draw_rect
+        .arguments
+        .iter()
+        .find(|argument| argument.name == "image")
+        .unwrap();
+    assert_eq!(
+        image.direction,
+        ArgumentDirection::Modify
+    );
+
+    assert!(introspection::operation("nosuchoperation").is_err());
+}
+
+#[test]
+fn loaders_and_savers_are_found_by_filename() {
+    let _app = common::app();
+    let path = format!(
+        "{}/examples/test.png",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    assert_eq!(
+        introspection::find_load(&path).as_deref(),
+        Some("pngload")
+    );
+    assert_eq!(
+        introspection::find_save("out.png").as_deref(),
+        Some("pngsave")
+    );
+    assert_eq!(
+        introspection::find_save("out.nosuchsuffix"),
+        None
+    );
+    assert!(
+        introspection::suffixes()
+            .iter()
+            .any(|suffix| suffix == ".png")
+    );
+}
+
+#[test]
+fn formats_are_detected_from_buffers_sources_and_files() {
+    let _app = common::app();
+    let path = format!(
+        "{}/examples/test.png",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let png = std::fs::read(&path).unwrap();
+    let image = VipsImage::new_from_buffer(&png, "").unwrap();
+    let jpeg = ops::jpegsave_buffer(&image).unwrap();
+
+    let detected = introspection::detect_format(&png).unwrap();
+    assert_eq!(
+        detected.format,
+        ImageFormat::Png
+    );
+    assert_eq!(
+        detected.loader,
+        "pngload_buffer"
+    );
+    assert_eq!(
+        introspection::detect_format(&jpeg)
+            .unwrap()
+            .format,
+        ImageFormat::Jpeg
+    );
+    assert_eq!(
+        introspection::detect_format_file(&path)
+            .unwrap()
+            .format,
+        ImageFormat::Png
+    );
+    assert_eq!(
+        introspection::detect_format(b"not an image"),
+        None
+    );
+
+    let source = VipsSource::new_from_memory(&jpeg).unwrap();
+    let detected = introspection::detect_format_source(&source).unwrap();
+    assert_eq!(
+        detected.format,
+        ImageFormat::Jpeg
+    );
+    assert_eq!(
+        detected.loader,
+        "jpegload_source"
+    );
+}
+
+#[test]
+fn sources_are_loaded_after_their_format_is_detected() {
+    let _app = common::app();
+    let path = format!(
+        "{}/examples/test.png",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let png = std::fs::read(&path).unwrap();
+    let expected = VipsImage::new_from_buffer(&png, "").unwrap();
+
+    let sources = [
+        VipsSource::new_from_file(&path).unwrap(),
+        VipsSource::from_reader(Cursor::new(png.clone())).unwrap(),
+        VipsSource::from_unseekable_reader(Cursor::new(png)).unwrap(),
+    ];
+    for source in &sources {
+        assert_eq!(
+            introspection::detect_format_source(source)
+                .unwrap()
+                .format,
+            ImageFormat::Png
+        );
+        let image = VipsImage::new_from_source(source, "").unwrap();
+        assert_eq!(
+            image
+                .image_write_to_memory()
+                .unwrap(),
+            expected
+                .image_write_to_memory()
+                .unwrap()
+        );
+    }
+}
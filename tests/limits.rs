@@ -0,0 +1,312 @@
+// (c) Copyright 2019-2026 OLX
+mod common;
+
+use libvips::error::Error;
+use libvips::{ops, LoadPolicy, Operation, VipsImage, VipsSource};
+use std::io::Cursor;
+
+fn test_image_path() -> String {
+    format!(
+        "{}/examples/test.png",
+        env!("CARGO_MANIFEST_DIR")
+    )
+}
+
+fn assert_limit<T>(result: libvips::Result<T>, expected: &str, scenario: &str) {
+    match result {
+        Err(Error::LimitExceeded {
+            limit,
+            ..
+        }) => assert_eq!(
+            limit, expected,
+            "{}",
+            scenario
+        ),
+        Err(e) => panic!(
+            "{} failed with {} instead of LimitExceeded",
+            scenario, e
+        ),
+        Ok(_) => panic!(
+            "{} was not limited",
+            scenario
+        ),
+    }
+}
+
+#[test]
+fn loads_exceeding_the_policy_fail_with_limit_exceeded() {
+    let app = common::app();
+    let buffer = std::fs::read(test_image_path()).unwrap();
+    let image = VipsImage::new_from_file(&test_image_path()).unwrap();
+    let width = image.get_width();
+    let height = image.get_height();
+
+    app.load_policy_set(&LoadPolicy::new().max_width(width - 1));
+    assert_limit(
+        VipsImage::new_from_file(&test_image_path()),
+        "width",
+        "new_from_file",
+    );
+    assert_limit(
+        ops::pngload(&test_image_path()),
+        "width",
+        "pngload",
+    );
+    assert_limit(
+        ops::pngload_with_opts(
+            &test_image_path(),
+            &ops::PngloadOptions::new(),
+        ),
+        "width",
+        "pngload_with_opts",
+    );
+
+    app.load_policy_set(&LoadPolicy::new().max_pixels(width as u64 * height as u64 - 1));
+    assert_limit(
+        VipsImage::new_from_buffer(
+            &buffer,
+            "",
+        ),
+        "pixels",
+        "new_from_buffer",
+    );
+    assert_limit(
+        ops::thumbnail_buffer(
+            &buffer,
+            16,
+        ),
+        "pixels",
+        "thumbnail_buffer",
+    );
+    let source = VipsSource::new_from_memory(&buffer).unwrap();
+    assert_limit(
+        ops::thumbnail_source(
+            &source,
+            16,
+        ),
+        "pixels",
+        "thumbnail_source",
+    );
+
+    app.load_policy_set(&LoadPolicy::new().max_bytes(buffer.len() as u64 - 1));
+    assert_limit(
+        ops::pngload_buffer(&buffer),
+        "bytes",
+        "pngload_buffer",
+    );
+    assert_limit(
+        ops::thumbnail_buffer(
+            &buffer,
+            16,
+        ),
+        "bytes",
+        "thumbnail_buffer",
+    );
+
+    // images within the limits load and the thumbnails are unaffected by the policy itself
+    app.load_policy_set(
+        &LoadPolicy::new()
+            .max_width(width)
+            .max_height(height)
+            .max_pages(1),
+    );
+    assert!(
+        VipsImage::new_from_buffer(
+            &buffer,
+            ""
+        )
+        .is_ok()
+    );
+    assert!(
+        ops::thumbnail_buffer(
+            &buffer,
+            16
+        )
+        .unwrap()
+        .get_width()
+            <= 16
+    );
+
+    app.load_policy_set(&LoadPolicy::new());
+    assert!(ops::pngload_buffer(&buffer).is_ok());
+}
+
+#[test]
+fn files_and_sources_exceeding_max_bytes_are_refused() {
+    let app = common::app();
+    let buffer = std::fs::read(test_image_path()).unwrap();
+    let size = buffer.len() as u64;
+
+    app.load_policy_set(&LoadPolicy::new().max_bytes(size - 1));
+    assert_limit(
+        VipsImage::new_from_file(&test_image_path()),
+        "bytes",
+        "new_from_file",
+    );
+    assert_limit(
+        ops::pngload(&test_image_path()),
+        "bytes",
+        "pngload",
+    );
+    assert_limit(
+        ops::thumbnail(
+            &test_image_path(),
+            16,
+        ),
+        "bytes",
+        "thumbnail",
+    );
+    let source = VipsSource::new_from_file(&test_image_path()).unwrap();
+    assert_limit(
+        VipsImage::new_from_source(
+            &source,
+            "",
+        ),
+        "bytes",
+        "new_from_source",
+    );
+    let source = VipsSource::from_reader(Cursor::new(buffer.clone())).unwrap();
+    assert_limit(
+        ops::pngload_source(&source),
+        "bytes",
+        "pngload_source",
+    );
+
+    app.load_policy_set(&LoadPolicy::new().max_bytes(size));
+    assert!(VipsImage::new_from_file(&test_image_path()).is_ok());
+    app.load_policy_set(&LoadPolicy::new());
+}
+
+#[test]
+fn unseekable_sources_are_checked_once() {
+    let app = common::app();
+    let buffer = std::fs::read(test_image_path()).unwrap();
+    let image = VipsImage::new_from_buffer(
+        &buffer,
+        "",
+    )
+    .unwrap();
+    let pixels = image.get_width() as u64 * image.get_height() as u64;
+
+    // the header checked is read from the same source the thumbnail loads
+    app.load_policy_set(&LoadPolicy::new().max_pixels(pixels));
+    let source = VipsSource::from_unseekable_reader(Cursor::new(buffer.clone())).unwrap();
+    assert!(
+        ops::thumbnail_source(
+            &source,
+            16
+        )
+        .unwrap()
+        .get_width()
+            <= 16
+    );
+
+    app.load_policy_set(&LoadPolicy::new().max_pixels(pixels - 1));
+    let source = VipsSource::from_unseekable_reader(Cursor::new(buffer)).unwrap();
+    assert_limit(
+        ops::thumbnail_source(
+            &source,
+            16,
+        ),
+        "pixels",
+        "thumbnail_source",
+    );
+    app.load_policy_set(&LoadPolicy::new());
+}
+
+#[test]
+fn unseekable_sources_fail_once_read_past_max_bytes() {
+    let app = common::app();
+    let buffer = std::fs::read(test_image_path()).unwrap();
+
+    // libvips reads the header in chunks larger than this
+    app.load_policy_set(&LoadPolicy::new().max_bytes(16));
+    let source = VipsSource::from_unseekable_reader(Cursor::new(buffer.clone())).unwrap();
+    assert_limit(
+        VipsImage::new_from_source(
+            &source,
+            "",
+        ),
+        "bytes",
+        "new_from_source",
+    );
+    let source = VipsSource::from_unseekable_reader(Cursor::new(buffer.clone())).unwrap();
+    assert_limit(
+        ops::thumbnail_source(
+            &source,
+            16,
+        ),
+        "bytes",
+        "thumbnail_source",
+    );
+
+    app.load_policy_set(&LoadPolicy::new().max_bytes(buffer.len() as u64));
+    let source = VipsSource::from_unseekable_reader(Cursor::new(buffer)).unwrap();
+    assert!(
+        ops::thumbnail_source(
+            &source,
+            16
+        )
+        .is_ok()
+    );
+    app.load_policy_set(&LoadPolicy::new());
+}
+
+#[test]
+fn loaders_called_by_nickname_are_checked() {
+    let app = common::app();
+    let buffer = std::fs::read(test_image_path()).unwrap();
+    let image = VipsImage::new_from_file(&test_image_path()).unwrap();
+
+    app.load_policy_set(&LoadPolicy::new().max_width(image.get_width() - 1));
+    assert_limit(
+        Operation::new("pngload")
+            .set(
+                "filename",
+                test_image_path().as_str(),
+            )
+            .call(),
+        "width",
+        "pngload",
+    );
+
+    app.load_policy_set(&LoadPolicy::new().max_bytes(buffer.len() as u64 - 1));
+    assert_limit(
+        Operation::new("pngload_buffer")
+            .set(
+                "buffer",
+                buffer.as_slice(),
+            )
+            .call(),
+        "bytes",
+        "pngload_buffer",
+    );
+    app.load_policy_set(&LoadPolicy::new().max_bytes(16));
+    let source = VipsSource::from_unseekable_reader(Cursor::new(buffer.clone())).unwrap();
+    assert_limit(
+        Operation::new("pngload_source")
+            .set(
+                "source",
+                &source,
+            )
+            .call(),
+        "bytes",
+        "pngload_source",
+    );
+
+    app.load_policy_set(&LoadPolicy::new());
+    let outputs = Operation::new("pngload_buffer")
+        .set(
+            "buffer",
+            buffer.as_slice(),
+        )
+        .call()
+        .unwrap();
+    assert_eq!(
+        outputs
+            .get_image("out")
+            .unwrap()
+            .get_width(),
+        image.get_width()
+    );
+}
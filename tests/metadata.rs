@@ -0,0 +1,207 @@
+// (c) Copyright 2019-2026 OLX
+mod common;
+
+use libvips::error::Error;
+use libvips::{ops, MetadataValue};
+
+#[test]
+fn getters_fail_with_typed_errors() {
+    let _app = common::app();
+    let mut image = ops::black(4, 4).unwrap();
+    image
+        .set_int(
+            "answer",
+            42,
+        )
+        .unwrap();
+    image
+        .set_double(
+            "ratio",
+            1.75,
+        )
+        .unwrap();
+
+    assert_eq!(
+        image
+            .get_int("answer")
+            .unwrap(),
+        42
+    );
+    assert_eq!(
+        image
+            .get_double("ratio")
+            .unwrap(),
+        1.75
+    );
+    // enums are read as their int value
+    assert!(image
+        .get_int("interpretation")
+        .is_ok());
+
+    // a double isn't truncated into an int
+    match image.get_int("ratio") {
+        Err(Error::FieldTypeError {
+            name,
+            expected,
+            ..
+        }) => {
+            assert_eq!(
+                name,
+                "ratio"
+            );
+            assert_eq!(
+                expected,
+                "int"
+            );
+        }
+        other => panic!(
+            "get_int of a double returned {:?}",
+            other
+        ),
+    }
+    assert!(matches!(
+        image.get_int("missing"),
+        Err(Error::MissingFieldError(_))
+    ));
+}
+
+#[test]
+fn setters_leave_cached_and_cloned_images_unchanged() {
+    let _app = common::app();
+    // the operation cache gives the same image to both calls
+    let mut image = ops::black(4, 4).unwrap();
+    image
+        .set_int(
+            "answer",
+            42,
+        )
+        .unwrap();
+    assert!(
+        ops::black(4, 4)
+            .unwrap()
+            .get_int("answer")
+            .is_err()
+    );
+
+    let mut cloned = image.clone();
+    cloned
+        .set_int(
+            "answer",
+            7,
+        )
+        .unwrap();
+    cloned
+        .set_string(
+            "note",
+            "cloned",
+        )
+        .unwrap();
+    assert_eq!(
+        image
+            .get_int("answer")
+            .unwrap(),
+        42
+    );
+    assert!(image
+        .get_string("note")
+        .is_err());
+    assert!(cloned
+        .remove("answer")
+        .unwrap());
+    assert_eq!(
+        image
+            .get_int("answer")
+            .unwrap(),
+        42
+    );
+}
+
+#[test]
+fn fields_return_the_values_set_for_each_kind() {
+    let _app = common::app();
+    let mut image = ops::black(4, 4).unwrap();
+    let thumbnail = ops::black(2, 3).unwrap();
+    let values = [
+        (
+            "rs-int",
+            MetadataValue::Int(42),
+        ),
+        (
+            "rs-double",
+            MetadataValue::Double(1.75),
+        ),
+        (
+            "rs-string",
+            MetadataValue::String(String::from("a note")),
+        ),
+        (
+            "rs-blob",
+            MetadataValue::Blob(vec![
+                0, 1, 2, 255,
+            ]),
+        ),
+        (
+            "rs-array-int",
+            MetadataValue::ArrayInt(vec![
+                -1, 0, 7,
+            ]),
+        ),
+        (
+            "rs-array-double",
+            MetadataValue::ArrayDouble(vec![
+                0.5, -2.25,
+            ]),
+        ),
+        (
+            "rs-image",
+            MetadataValue::Image(thumbnail),
+        ),
+    ];
+    for (name, value) in &values {
+        image
+            .set_field(
+                name, value,
+            )
+            .unwrap();
+    }
+
+    let fields = image
+        .fields()
+        .unwrap();
+    for (name, value) in &values {
+        match (
+            value,
+            &fields[*name],
+        ) {
+            (MetadataValue::Int(set), MetadataValue::Int(read)) => assert_eq!(set, read),
+            (MetadataValue::Double(set), MetadataValue::Double(read)) => assert_eq!(set, read),
+            (MetadataValue::String(set), MetadataValue::String(read)) => assert_eq!(set, read),
+            (MetadataValue::Blob(set), MetadataValue::Blob(read)) => assert_eq!(set, read),
+            (MetadataValue::ArrayInt(set), MetadataValue::ArrayInt(read)) => assert_eq!(set, read),
+            (MetadataValue::ArrayDouble(set), MetadataValue::ArrayDouble(read)) => {
+                assert_eq!(set, read)
+            }
+            (MetadataValue::Image(set), MetadataValue::Image(read)) => {
+                assert_eq!(
+                    (
+                        read.get_width(),
+                        read.get_height()
+                    ),
+                    (
+                        set.get_width(),
+                        set.get_height()
+                    )
+                )
+            }
+            (set, read) => panic!(
+                "{} was set as {:?} and read as {:?}",
+                name, set, read
+            ),
+        }
+    }
+    // the header fields are listed with the ones set
+    assert!(matches!(
+        fields["width"],
+        MetadataValue::Int(4)
+    ));
+}
@@ -0,0 +1,200 @@
+// (c) Copyright 2019-2026 OLX
+mod common;
+
+use libvips::error::Error;
+use libvips::ops::{self, Direction};
+use libvips::{Operation, VipsImage};
+
+fn test_image_path() -> String {
+    format!(
+        "{}/examples/test.png",
+        env!("CARGO_MANIFEST_DIR")
+    )
+}
+
+fn pixels(image: &VipsImage) -> Vec<u8> {
+    image
+        .image_write_to_memory()
+        .unwrap()
+}
+
+#[test]
+fn operations_match_their_ops_wrappers() {
+    let _app = common::app();
+    let image = VipsImage::new_from_file(&test_image_path()).unwrap();
+
+    let outputs = Operation::new("invert")
+        .set(
+            "in",
+            &image,
+        )
+        .call()
+        .unwrap();
+    assert_eq!(
+        pixels(
+            &outputs
+                .get_image("out")
+                .unwrap()
+        ),
+        pixels(&ops::invert(&image).unwrap())
+    );
+
+    // enums are given by the nickname of their value
+    let outputs = Operation::new("flip")
+        .set(
+            "in",
+            &image,
+        )
+        .set(
+            "direction",
+            "horizontal",
+        )
+        .call()
+        .unwrap();
+    assert_eq!(
+        pixels(
+            &outputs
+                .get_image("out")
+                .unwrap()
+        ),
+        pixels(
+            &ops::flip(
+                &image,
+                Direction::Horizontal
+            )
+            .unwrap()
+        )
+    );
+
+    let outputs = Operation::new("avg")
+        .set(
+            "in",
+            &image,
+        )
+        .call()
+        .unwrap();
+    assert_eq!(
+        outputs
+            .get_double("out")
+            .unwrap(),
+        ops::avg(&image).unwrap()
+    );
+
+    let outputs = Operation::new("pngsave_buffer")
+        .set(
+            "in",
+            &image,
+        )
+        .call()
+        .unwrap();
+    let saved = VipsImage::new_from_buffer(
+        &outputs
+            .get_blob("buffer")
+            .unwrap(),
+        "",
+    )
+    .unwrap();
+    assert_eq!(
+        pixels(&saved),
+        pixels(&image)
+    );
+}
+
+#[test]
+fn draw_operations_draw_on_a_copy() {
+    let _app = common::app();
+    let image = ops::black(20, 20).unwrap();
+
+    let outputs = Operation::new("draw_rect")
+        .set(
+            "image",
+            &image,
+        )
+        .set(
+            "ink",
+            &[255.0][..],
+        )
+        .set("left", 2)
+        .set("top", 3)
+        .set("width", 4)
+        .set(
+            "height",
+            5,
+        )
+        .call()
+        .unwrap();
+    let drawn = outputs
+        .get_image("image")
+        .unwrap();
+
+    let mut expected = ops::black(20, 20).unwrap();
+    ops::draw_rect(
+        &mut expected,
+        &mut [255.0],
+        2,
+        3,
+        4,
+        5,
+    )
+    .unwrap();
+    assert_eq!(
+        pixels(&drawn),
+        pixels(&expected)
+    );
+    assert_eq!(
+        ops::avg(&drawn).unwrap(),
+        255.0 * 20.0 / 400.0
+    );
+    assert_eq!(
+        ops::avg(&image).unwrap(),
+        0.0
+    );
+}
+
+#[test]
+fn unknown_arguments_and_mismatched_types_fail() {
+    let _app = common::app();
+    let image = ops::black(20, 20).unwrap();
+
+    assert!(
+        Operation::new("nosuchoperation")
+            .call()
+            .is_err()
+    );
+    assert!(
+        Operation::new("invert")
+            .set(
+                "nosuchargument",
+                &image
+            )
+            .call()
+            .is_err()
+    );
+    assert!(
+        Operation::new("invert")
+            .set(
+                "in",
+                "not an image"
+            )
+            .call()
+            .is_err()
+    );
+
+    let outputs = Operation::new("invert")
+        .set(
+            "in",
+            &image,
+        )
+        .call()
+        .unwrap();
+    match outputs.get_int("out") {
+        Err(Error::ArgumentTypeError {
+            expected,
+            ..
+        }) => assert_eq!(
+            expected,
+            "i32"
+        ),
+        _ => panic!("the image output was read as an i32"),
+    }
+}
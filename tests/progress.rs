@@ -0,0 +1,125 @@
+// (c) Copyright 2019-2026 OLX
+mod common;
+
+use libvips::error::Error;
+use libvips::{ops, EvalAction, EvalStage, VipsImage};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+fn assert_cancelled<T>(result: libvips::Result<T>, scenario: &str) {
+    match result {
+        Err(Error::Cancelled) => (),
+        Err(e) => panic!(
+            "{} failed with {} instead of Cancelled",
+            scenario, e
+        ),
+        Ok(_) => panic!(
+            "{} was not cancelled",
+            scenario
+        ),
+    }
+}
+
+// large enough for libvips to compute it in several parts, each reported with `EvalStage::Progress`
+fn large_image() -> VipsImage {
+    ops::black(2000, 2000).unwrap()
+}
+
+#[test]
+fn cancelling_the_evaluation_fails_the_save() {
+    let _app = common::app();
+    let mut image = large_image();
+
+    image
+        .set_progress_callback(
+            |stage, _| {
+                if stage == EvalStage::Start {
+                    EvalAction::Cancel
+                } else {
+                    EvalAction::Continue
+                }
+            },
+        )
+        .unwrap();
+    assert_cancelled(
+        image.image_write_to_buffer(".png"),
+        "cancelled at start",
+    );
+
+    // the new callback replaces the cancelling one
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = calls.clone();
+    image
+        .set_progress_callback(
+            move |_, _| {
+                counter.fetch_add(
+                    1,
+                    Ordering::SeqCst,
+                );
+                EvalAction::Continue
+            },
+        )
+        .unwrap();
+    assert!(image
+        .image_write_to_buffer(".png")
+        .is_ok());
+    assert!(calls.load(Ordering::SeqCst) > 0);
+
+    image
+        .clear_progress_callback()
+        .unwrap();
+    let seen = calls.load(Ordering::SeqCst);
+    assert!(image
+        .image_write_to_buffer(".png")
+        .is_ok());
+    assert_eq!(
+        calls.load(Ordering::SeqCst),
+        seen
+    );
+}
+
+#[test]
+fn cancelling_during_the_evaluation_fails_every_evaluating_call() {
+    let _app = common::app();
+    let mut image = large_image();
+    image
+        .set_progress_callback(
+            |stage, _| {
+                if stage == EvalStage::Progress {
+                    EvalAction::Cancel
+                } else {
+                    EvalAction::Continue
+                }
+            },
+        )
+        .unwrap();
+
+    assert_cancelled(
+        image.image_write_to_buffer(".png"),
+        "image_write_to_buffer",
+    );
+    assert_cancelled(
+        image.image_write_to_memory(),
+        "image_write_to_memory",
+    );
+    assert_cancelled(
+        VipsImage::image_copy_memory(image.clone()),
+        "image_copy_memory",
+    );
+
+    // a panicking callback cancels the evaluation instead of unwinding into libvips
+    image
+        .set_progress_callback(
+            |stage, _| {
+                if stage == EvalStage::Progress {
+                    panic!("callback panicked")
+                }
+                EvalAction::Continue
+            },
+        )
+        .unwrap();
+    assert_cancelled(
+        image.image_write_to_memory(),
+        "panicking callback",
+    );
+}
@@ -0,0 +1,137 @@
+// (c) Copyright 2019-2026 OLX
+mod common;
+
+use libvips::ops::{self, BandFormat};
+use libvips::{Operation, VipsImage, VipsRegion};
+
+// an image with a different value in each band of each pixel: x * a + b in the first band and
+// y * a + b in the second
+fn gradient(a: f64, b: f64, format: BandFormat) -> VipsImage {
+    let xyz = ops::xyz(32, 24).unwrap();
+    ops::cast(
+        &ops::linear(
+            &xyz,
+            &mut [a],
+            &mut [b],
+        )
+        .unwrap(),
+        format,
+    )
+    .unwrap()
+}
+
+fn getpoint(image: &VipsImage, x: i32, y: i32) -> Vec<f64> {
+    Operation::new("getpoint")
+        .set(
+            "in", image,
+        )
+        .set("x", x)
+        .set("y", y)
+        .call()
+        .unwrap()
+        .get_array_double("out_array")
+        .unwrap()
+}
+
+// reads the window at (3, 5) of 7 by 4 pixels and compares each value with getpoint
+fn assert_window<T: libvips::BandFormatType + Into<f64> + PartialEq + std::fmt::Debug>(
+    image: &VipsImage,
+) {
+    let mut region = VipsRegion::new(image).unwrap();
+    region
+        .prepare(3, 5, 7, 4)
+        .unwrap();
+    assert_eq!(
+        (
+            region.left(),
+            region.top(),
+            region.width(),
+            region.height()
+        ),
+        (3, 5, 7, 4)
+    );
+
+    let rows = region
+        .rows::<T>()
+        .unwrap();
+    assert_eq!(
+        rows.len(),
+        4
+    );
+    for (y, row) in (5..).zip(&rows) {
+        assert_eq!(
+            row.len(),
+            7 * 2
+        );
+        for (x, pixel) in (3..).zip(row.chunks(2)) {
+            let pixel: Vec<f64> = pixel
+                .iter()
+                .map(|&value| value.into())
+                .collect();
+            assert_eq!(
+                pixel,
+                getpoint(image, x, y),
+                "pixel ({}, {})",
+                x,
+                y
+            );
+        }
+        assert_eq!(
+            region
+                .row::<T>(y)
+                .unwrap(),
+            *row
+        );
+    }
+    assert_eq!(
+        region
+            .to_vec::<T>()
+            .unwrap(),
+        rows.concat()
+    );
+}
+
+#[test]
+fn windows_are_read_with_the_values_of_the_image() {
+    let _app = common::app();
+    assert_window::<u16>(&gradient(
+        300.0,
+        7.0,
+        BandFormat::Ushort,
+    ));
+    assert_window::<f32>(&gradient(
+        0.5,
+        0.25,
+        BandFormat::Float,
+    ));
+}
+
+#[test]
+fn lines_outside_the_prepared_area_are_refused() {
+    let _app = common::app();
+    let image = gradient(
+        1.0,
+        0.0,
+        BandFormat::Ushort,
+    );
+    let mut region = VipsRegion::new(&image).unwrap();
+    assert!(region
+        .row::<u16>(0)
+        .is_err());
+
+    region
+        .prepare(3, 5, 7, 4)
+        .unwrap();
+    assert!(region
+        .row::<u16>(4)
+        .is_err());
+    assert!(region
+        .row::<u16>(9)
+        .is_err());
+    assert!(region
+        .row::<u16>(8)
+        .is_ok());
+    assert!(region
+        .row::<f32>(5)
+        .is_err());
+}
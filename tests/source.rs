@@ -0,0 +1,90 @@
+// (c) Copyright 2019-2026 OLX
+mod common;
+
+use libvips::{ops, VipsImage, VipsSource};
+use std::io::{Cursor, Read};
+
+fn test_image_path() -> String {
+    format!(
+        "{}/examples/test.png",
+        env!("CARGO_MANIFEST_DIR")
+    )
+}
+
+// the pixels of the image as loaded from the file, to compare the other loads with
+fn same_pixels(image: &VipsImage, expected: &VipsImage) {
+    assert_eq!(
+        image.get_width(),
+        expected.get_width()
+    );
+    assert_eq!(
+        image.get_height(),
+        expected.get_height()
+    );
+    assert_eq!(
+        image
+            .image_write_to_memory()
+            .unwrap(),
+        expected
+            .image_write_to_memory()
+            .unwrap()
+    );
+}
+
+struct FailingReader;
+
+impl Read for FailingReader {
+    fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::other("connection reset"))
+    }
+}
+
+struct PanickingReader;
+
+impl Read for PanickingReader {
+    fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+        panic!("reader panicked")
+    }
+}
+
+#[test]
+fn readers_are_loaded_like_files() {
+    let _app = common::app();
+    let buffer = std::fs::read(test_image_path()).unwrap();
+    let expected = VipsImage::new_from_file(&test_image_path()).unwrap();
+
+    let source = VipsSource::from_reader(Cursor::new(buffer.clone())).unwrap();
+    same_pixels(
+        &ops::pngload_source(&source).unwrap(),
+        &expected,
+    );
+
+    // without seeking, libvips keeps the header it sniffed in memory and reads it again from there
+    let source = VipsSource::from_unseekable_reader(Cursor::new(buffer)).unwrap();
+    same_pixels(
+        &VipsImage::new_from_source(
+            &source,
+            "",
+        )
+        .unwrap(),
+        &expected,
+    );
+}
+
+#[test]
+fn failing_readers_fail_the_load() {
+    let _app = common::app();
+
+    let source = VipsSource::from_unseekable_reader(FailingReader).unwrap();
+    assert!(ops::pngload_source(&source).is_err());
+
+    // the panic is caught in the read handler and reported to libvips as a failed read
+    let source = VipsSource::from_unseekable_reader(PanickingReader).unwrap();
+    assert!(
+        VipsImage::new_from_source(
+            &source,
+            ""
+        )
+        .is_err()
+    );
+}
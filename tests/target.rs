@@ -0,0 +1,193 @@
+// (c) Copyright 2019-2026 OLX
+mod common;
+
+use libvips::{ops, VipsImage, VipsSource, VipsTarget};
+use std::io::{Cursor, Read, Seek, SeekFrom, Write};
+use std::sync::{Arc, Mutex};
+
+fn test_image_path() -> String {
+    format!(
+        "{}/examples/test.png",
+        env!("CARGO_MANIFEST_DIR")
+    )
+}
+
+// a cursor the test keeps a handle on after moving it into the target
+#[derive(Clone, Default)]
+struct SharedCursor(Arc<Mutex<Cursor<Vec<u8>>>>);
+
+impl SharedCursor {
+    fn contents(&self) -> Vec<u8> {
+        self.0
+            .lock()
+            .unwrap()
+            .get_ref()
+            .clone()
+    }
+}
+
+impl Read for SharedCursor {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.0
+            .lock()
+            .unwrap()
+            .read(buf)
+    }
+}
+
+impl Write for SharedCursor {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0
+            .lock()
+            .unwrap()
+            .write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Seek for SharedCursor {
+    fn seek(&mut self, position: SeekFrom) -> std::io::Result<u64> {
+        self.0
+            .lock()
+            .unwrap()
+            .seek(position)
+    }
+}
+
+struct FailingWriter;
+
+impl Write for FailingWriter {
+    fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::other("disk full"))
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+struct PanickingWriter;
+
+impl Write for PanickingWriter {
+    fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+        panic!("writer panicked")
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+fn round_trip(written: &[u8], expected: &VipsImage) {
+    let source = VipsSource::from_reader(Cursor::new(written.to_vec())).unwrap();
+    let image = VipsImage::new_from_source(
+        &source,
+        "",
+    )
+    .unwrap();
+    assert_eq!(
+        image.get_width(),
+        expected.get_width()
+    );
+    assert_eq!(
+        image.get_height(),
+        expected.get_height()
+    );
+    assert_eq!(
+        image
+            .image_write_to_memory()
+            .unwrap(),
+        expected
+            .image_write_to_memory()
+            .unwrap()
+    );
+}
+
+#[test]
+fn writers_receive_the_saved_image() {
+    let _app = common::app();
+    let image = VipsImage::new_from_file(&test_image_path()).unwrap();
+
+    let cursor = SharedCursor::default();
+    let target = VipsTarget::from_writer(cursor.clone()).unwrap();
+    ops::pngsave_target(
+        &image,
+        &target,
+    )
+    .unwrap();
+    drop(target);
+    round_trip(
+        &cursor.contents(),
+        &image,
+    );
+
+    // the tiff saver seeks back to write the offsets of the directories
+    let cursor = SharedCursor::default();
+    let target = VipsTarget::from_seekable_writer(cursor.clone()).unwrap();
+    ops::tiffsave_target(
+        &image,
+        &target,
+    )
+    .unwrap();
+    drop(target);
+    round_trip(
+        &cursor.contents(),
+        &image,
+    );
+}
+
+#[test]
+fn failing_writers_fail_the_save() {
+    let _app = common::app();
+    let image = VipsImage::new_from_file(&test_image_path()).unwrap();
+
+    let target = VipsTarget::from_writer(FailingWriter).unwrap();
+    assert!(
+        ops::pngsave_target(
+            &image,
+            &target
+        )
+        .is_err()
+    );
+
+    // the panic is caught in the write handler, unwinding into libvips would abort the process
+    let target = VipsTarget::from_writer(PanickingWriter).unwrap();
+    assert!(
+        ops::pngsave_target(
+            &image,
+            &target
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn sources_are_saved_to_targets_by_suffix() {
+    let _app = common::app();
+    let expected = VipsImage::new_from_file(&test_image_path()).unwrap();
+    let buffer = std::fs::read(test_image_path()).unwrap();
+
+    // the loader and the saver are picked from the content of the source and from the suffix
+    let source = VipsSource::new_from_memory(&buffer).unwrap();
+    let image = VipsImage::new_from_source(
+        &source,
+        "",
+    )
+    .unwrap();
+    let target = VipsTarget::new_to_memory().unwrap();
+    image
+        .image_write_to_target(
+            ".png[compression=9]",
+            &target,
+        )
+        .unwrap();
+    round_trip(
+        &target
+            .steal()
+            .unwrap(),
+        &expected,
+    );
+}